@@ -1,7 +1,9 @@
 use sp_core::{Pair, Public, sr25519};
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig,
-	SudoConfig, SystemConfig, WASM_BINARY, Signature
+	AccountId, AuraConfig, BalancesConfig, ExistentialDeposit, GenesisConfig, GrandpaConfig,
+	KittiesModuleConfig, MarketplaceFeeToAccount, PoeModuleConfig, SudoConfig, SystemConfig,
+	WASM_BINARY, Signature,
+	pallet_kitties::genome, pallet_poe::{ClaimDigest, HashAlgorithm},
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_finality_grandpa::AuthorityId as GrandpaId;
@@ -140,8 +142,11 @@ fn testnet_genesis(
 			changes_trie_config: Default::default(),
 		},
 		balances: BalancesConfig {
-			// Configure endowed accounts with initial balance of 1 << 60.
-			balances: endowed_accounts.iter().cloned().map(|k|(k, 1 << 60)).collect(),
+			// Configure endowed accounts with initial balance of 1 << 60, and keep the
+			// marketplace fee treasury alive so that fees below the existential deposit are kept.
+			balances: endowed_accounts.iter().cloned().map(|k|(k, 1 << 60))
+				.chain(Some((MarketplaceFeeToAccount::treasury_account(), ExistentialDeposit::get())))
+				.collect(),
 		},
 		aura: AuraConfig {
 			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect(),
@@ -153,5 +158,18 @@ fn testnet_genesis(
 			// Assign network admin rights.
 			key: root_key,
 		},
+		kitties_module: KittiesModuleConfig {
+			// Pre-mint one kitty for each of the first endowed accounts.
+			kitties: endowed_accounts.iter().take(2).cloned().enumerate()
+				.map(|(i, owner)| (owner, genome::random_genome([i as u8 + 1; 16])))
+				.collect(),
+		},
+		poe_module: PoeModuleConfig {
+			// Pre-register a sample proof owned by the first endowed account.
+			claims: endowed_accounts.first().cloned()
+				.map(|owner| (owner, ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"genesis proof")))
+				.into_iter()
+				.collect(),
+		},
 	}
 }
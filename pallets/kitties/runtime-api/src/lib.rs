@@ -0,0 +1,25 @@
+//! Runtime API definition for the kitties pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+pub use pallet_kitties::genome::{Color, Eyes, KittyTraits, Pattern};
+pub use pallet_kitties::SupplyStats;
+
+sp_api::decl_runtime_apis! {
+	pub trait KittiesApi<KittyIndex> where
+		KittyIndex: Codec,
+	{
+		/// Decode the expressed traits of kitty `kitty_id`, or `None` if it does not exist.
+		fn decode_traits(kitty_id: KittyIndex) -> Option<KittyTraits>;
+
+		/// Walk the ancestry of `kitty_id` up to `depth` generations, returning each visited
+		/// kitty with its parents. The depth is capped by the pallet's `MaxAncestryDepth`.
+		fn ancestry(kitty_id: KittyIndex, depth: u32) -> Vec<(KittyIndex, Option<(KittyIndex, KittyIndex)>)>;
+
+		/// Supply statistics: kitties minted and burned so far, kitties alive, and the live
+		/// count of each generation sorted by generation.
+		fn supply_stats() -> SupplyStats;
+	}
+}
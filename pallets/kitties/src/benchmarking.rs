@@ -0,0 +1,356 @@
+//! Benchmarking setup for pallet-kitties
+
+use super::*;
+
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, impl_benchmark_test_suite};
+use frame_support::traits::{Currency, Hooks, ReservableCurrency};
+use frame_support::storage::bounded_vec::BoundedVec;
+use sp_runtime::traits::Bounded;
+use sp_std::{convert::TryFrom, vec, vec::Vec};
+#[allow(unused)]
+use crate::Pallet as Kitties;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, 0);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	caller
+}
+
+fn create_kitty<T: Config>(owner: &T::AccountId) -> Result<T::KittyIndex, &'static str> {
+	let kitty_id = Kitties::<T>::next_id();
+	Kitties::<T>::create(RawOrigin::Signed(owner.clone()).into())?;
+	Ok(kitty_id)
+}
+
+//让Kitty处于出售和出租繁殖状态，这是转移、接受报价和销毁允许的锁定状态中开销最大的情况
+fn list_and_offer_siring<T: Config>(owner: &T::AccountId, kitty_id: T::KittyIndex) -> Result<(), &'static str> {
+	Kitties::<T>::sell_kitty(RawOrigin::Signed(owner.clone()).into(), kitty_id, T::Currency::minimum_balance())?;
+	Kitties::<T>::offer_siring(RawOrigin::Signed(owner.clone()).into(), kitty_id, T::Currency::minimum_balance())?;
+	Ok(())
+}
+
+benchmarks! {
+	create {
+		let caller = funded_caller::<T>();
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Owner::<T>::get(T::KittyIndex::from(1u32)), Some(caller));
+	}
+
+	transfer {
+		let caller = funded_caller::<T>();
+		let recipient = funded_account::<T>("recipient", 0);
+		let kitty_id = create_kitty::<T>(&caller)?;
+		list_and_offer_siring::<T>(&caller, kitty_id)?;
+	}: _(RawOrigin::Signed(caller), recipient.clone(), kitty_id)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(recipient));
+		assert_eq!(KittiesPrice::<T>::get(kitty_id), None);
+		assert!(!SiringOffers::<T>::contains_key(kitty_id));
+	}
+
+	sell_kitty {
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&caller)?;
+		let price = T::Currency::minimum_balance();
+	}: _(RawOrigin::Signed(caller), kitty_id, price)
+	verify {
+		assert_eq!(KittiesPrice::<T>::get(kitty_id), Some(price));
+	}
+
+	cancel_sale {
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&caller)?;
+		Kitties::<T>::sell_kitty(RawOrigin::Signed(caller.clone()).into(), kitty_id, T::Currency::minimum_balance())?;
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert_eq!(KittiesPrice::<T>::get(kitty_id), None);
+	}
+
+	buy_kitty {
+		//创作者和卖家不同，成交时需要支付版税
+		let creator = funded_account::<T>("creator", 0);
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = create_kitty::<T>(&creator)?;
+		Kitties::<T>::transfer(RawOrigin::Signed(creator).into(), seller.clone(), kitty_id)?;
+		let price = T::Currency::minimum_balance() * 100u32.into();
+		//出售状态是购买唯一允许的锁定状态
+		Kitties::<T>::sell_kitty(RawOrigin::Signed(seller).into(), kitty_id, price)?;
+		let caller = funded_caller::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(caller));
+	}
+
+	breed {
+		let caller = funded_caller::<T>();
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id_1 = create_kitty::<T>(&caller)?;
+		let kitty_id_2 = create_kitty::<T>(&owner)?;
+		//最坏情况：父方通过授权使用，父母都处于允许繁殖的出租繁殖状态
+		Kitties::<T>::approve_siring(RawOrigin::Signed(owner.clone()).into(), kitty_id_2, Some(caller.clone()))?;
+		Kitties::<T>::offer_siring(RawOrigin::Signed(caller.clone()).into(), kitty_id_1, T::Currency::minimum_balance())?;
+		Kitties::<T>::offer_siring(RawOrigin::Signed(owner).into(), kitty_id_2, T::Currency::minimum_balance())?;
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id_1, kitty_id_2)
+	verify {
+		assert!(
+			Pregnancies::<T>::contains_key(kitty_id_1) ||
+			Owner::<T>::get(kitty_id_2 + 1u32.into()) == Some(caller)
+		);
+		assert!(!SiringApprovals::<T>::contains_key(kitty_id_2));
+	}
+
+	approve_siring {
+		let caller = funded_caller::<T>();
+		let breeder: T::AccountId = account("breeder", 0, 0);
+		let sire_id = create_kitty::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), sire_id, Some(breeder.clone()))
+	verify {
+		assert_eq!(SiringApprovals::<T>::get(sire_id), Some(breeder));
+	}
+
+	offer_siring {
+		let caller = funded_caller::<T>();
+		let sire_id = create_kitty::<T>(&caller)?;
+		let stud_fee = T::Currency::minimum_balance();
+	}: _(RawOrigin::Signed(caller), sire_id, stud_fee)
+	verify {
+		assert_eq!(SiringOffers::<T>::get(sire_id), Some(stud_fee));
+	}
+
+	cancel_siring_offer {
+		let caller = funded_caller::<T>();
+		let sire_id = create_kitty::<T>(&caller)?;
+		Kitties::<T>::offer_siring(RawOrigin::Signed(caller.clone()).into(), sire_id, T::Currency::minimum_balance())?;
+	}: _(RawOrigin::Signed(caller), sire_id)
+	verify {
+		assert!(!SiringOffers::<T>::contains_key(sire_id));
+	}
+
+	breed_with_sire {
+		let owner = funded_account::<T>("owner", 0);
+		let sire_id = create_kitty::<T>(&owner)?;
+		let stud_fee = T::Currency::minimum_balance();
+		Kitties::<T>::offer_siring(RawOrigin::Signed(owner).into(), sire_id, stud_fee)?;
+		let caller = funded_caller::<T>();
+		let matron_id = create_kitty::<T>(&caller)?;
+		//母方也处于允许繁殖的出租繁殖状态
+		Kitties::<T>::offer_siring(RawOrigin::Signed(caller.clone()).into(), matron_id, stud_fee)?;
+	}: _(RawOrigin::Signed(caller.clone()), matron_id, sire_id, stud_fee)
+	verify {
+		assert!(
+			Pregnancies::<T>::contains_key(matron_id) ||
+			Owner::<T>::get(matron_id + 1u32.into()) == Some(caller)
+		);
+	}
+
+	create_auction {
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&caller)?;
+		let kind = AuctionKind::English { starting_price: T::Currency::minimum_balance() };
+	}: _(RawOrigin::Signed(caller), kitty_id, kind, T::MaxAuctionDuration::get())
+	verify {
+		assert!(Auctions::<T>::contains_key(kitty_id));
+	}
+
+	bid {
+		//最坏情况：荷式拍卖按当前价格立即成交并转移Kitty，创作者和卖家不同需要支付版税
+		let creator = funded_account::<T>("creator", 0);
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = create_kitty::<T>(&creator)?;
+		Kitties::<T>::transfer(RawOrigin::Signed(creator).into(), seller.clone(), kitty_id)?;
+		let price = T::Currency::minimum_balance() * 100u32.into();
+		let kind = AuctionKind::Dutch { start_price: price, end_price: price };
+		Kitties::<T>::create_auction(RawOrigin::Signed(seller).into(), kitty_id, kind, T::MaxAuctionDuration::get())?;
+		let caller = funded_caller::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(caller));
+		assert!(!Auctions::<T>::contains_key(kitty_id));
+	}
+
+	cancel_auction {
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&caller)?;
+		let kind = AuctionKind::English { starting_price: T::Currency::minimum_balance() };
+		Kitties::<T>::create_auction(RawOrigin::Signed(caller.clone()).into(), kitty_id, kind, T::MaxAuctionDuration::get())?;
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(!Auctions::<T>::contains_key(kitty_id));
+	}
+
+	on_finalize {
+		let a in 0 .. T::MaxAuctionsPerBlock::get();
+		let price = T::Currency::minimum_balance();
+		let duration: T::BlockNumber = 1u32.into();
+		for i in 0 .. a {
+			//创作者和卖家不同，结算时需要支付版税
+			let creator = funded_account::<T>("creator", i);
+			let seller = funded_account::<T>("seller", i);
+			let kitty_id = create_kitty::<T>(&creator)?;
+			Kitties::<T>::transfer(RawOrigin::Signed(creator).into(), seller.clone(), kitty_id)?;
+			let kind = AuctionKind::English { starting_price: price };
+			Kitties::<T>::create_auction(RawOrigin::Signed(seller).into(), kitty_id, kind, duration)?;
+			let bidder = funded_account::<T>("bidder", i);
+			Kitties::<T>::bid(RawOrigin::Signed(bidder).into(), kitty_id, price)?;
+		}
+		let end = frame_system::Pallet::<T>::block_number() + duration.max(T::AntiSnipeDuration::get());
+	}: {
+		Kitties::<T>::on_finalize(end);
+	}
+	verify {
+		assert!(AuctionEndings::<T>::get(end).is_empty());
+		assert_eq!(Auctions::<T>::iter().count(), 0);
+	}
+
+	make_offer {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = create_kitty::<T>(&owner)?;
+		let caller = funded_caller::<T>();
+		let amount = T::Currency::minimum_balance();
+		//最坏情况：报价已满且没有过期的报价，需要移除最低的报价
+		for i in 0 .. T::MaxOffersPerKitty::get() {
+			let bidder = funded_account::<T>("bidder", i);
+			Kitties::<T>::make_offer(RawOrigin::Signed(bidder).into(), kitty_id, amount, T::MaxOfferDuration::get())?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, amount * 2u32.into(), T::MaxOfferDuration::get())
+	verify {
+		assert_eq!(Offers::<T>::get(kitty_id).len() as u32, T::MaxOffersPerKitty::get());
+		assert!(Offers::<T>::get(kitty_id).iter().any(|offer| offer.bidder == caller));
+	}
+
+	withdraw_offer {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = create_kitty::<T>(&owner)?;
+		let caller = funded_caller::<T>();
+		Kitties::<T>::make_offer(RawOrigin::Signed(caller.clone()).into(), kitty_id, T::Currency::minimum_balance(), T::MaxOfferDuration::get())?;
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert!(OffersOf::<T>::get(&caller).is_empty());
+	}
+
+	accept_offer {
+		//创作者和拥有者不同，成交时需要支付版税
+		let creator = funded_account::<T>("creator", 0);
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&creator)?;
+		Kitties::<T>::transfer(RawOrigin::Signed(creator).into(), caller.clone(), kitty_id)?;
+		list_and_offer_siring::<T>(&caller, kitty_id)?;
+		let bidder = funded_account::<T>("bidder", 0);
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		Kitties::<T>::make_offer(RawOrigin::Signed(bidder.clone()).into(), kitty_id, amount, T::MaxOfferDuration::get())?;
+	}: _(RawOrigin::Signed(caller), kitty_id, bidder.clone())
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(bidder));
+		assert_eq!(KittiesPrice::<T>::get(kitty_id), None);
+	}
+
+	burn {
+		let o in 0 .. T::MaxOffersPerKitty::get();
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&caller)?;
+		//最坏情况：Kitty处于出售和出租繁殖状态并且有名字，销毁时一并清除
+		list_and_offer_siring::<T>(&caller, kitty_id)?;
+		Kitties::<T>::set_name(RawOrigin::Signed(caller.clone()).into(), kitty_id, vec![b'k'; T::MaxNameLength::get() as usize])?;
+		//销毁时需要退还每一个报价
+		for i in 0 .. o {
+			let bidder = funded_account::<T>("bidder", i);
+			Kitties::<T>::make_offer(RawOrigin::Signed(bidder).into(), kitty_id, T::Currency::minimum_balance(), T::MaxOfferDuration::get())?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), None);
+		assert_eq!(T::Currency::reserved_balance(&caller), 0u32.into());
+		assert!(Offers::<T>::get(kitty_id).is_empty());
+		assert!(!MetadataOf::<T>::contains_key(kitty_id));
+	}
+
+	approve {
+		let caller = funded_caller::<T>();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		let kitty_id = create_kitty::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), kitty_id, Some(delegate.clone()))
+	verify {
+		assert_eq!(Approvals::<T>::get(kitty_id), Some(delegate));
+	}
+
+	set_approval_for_all {
+		let caller = funded_caller::<T>();
+		let operator: T::AccountId = account("operator", 0, 0);
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone(), true)
+	verify {
+		assert!(OperatorApprovals::<T>::get(&caller, &operator));
+	}
+
+	transfer_from {
+		let owner = funded_account::<T>("owner", 0);
+		let recipient = funded_account::<T>("recipient", 0);
+		let kitty_id = create_kitty::<T>(&owner)?;
+		list_and_offer_siring::<T>(&owner, kitty_id)?;
+		let caller = funded_caller::<T>();
+		Kitties::<T>::set_approval_for_all(RawOrigin::Signed(owner.clone()).into(), caller.clone(), true)?;
+	}: _(RawOrigin::Signed(caller), owner, recipient.clone(), kitty_id)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(recipient));
+		assert_eq!(KittiesPrice::<T>::get(kitty_id), None);
+	}
+
+	set_name {
+		let n in 1 .. T::MaxNameLength::get();
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&caller)?;
+		let name = vec![b'k'; n as usize];
+	}: _(RawOrigin::Signed(caller), kitty_id, name.clone())
+	verify {
+		assert_eq!(MetadataOf::<T>::get(kitty_id).map(|m| m.name.into_inner()), Some(name));
+	}
+
+	set_metadata {
+		let n in 1 .. T::MaxMetadataLength::get();
+		let caller = funded_caller::<T>();
+		let kitty_id = create_kitty::<T>(&caller)?;
+		let metadata_uri = vec![b'u'; n as usize];
+	}: _(RawOrigin::Signed(caller), kitty_id, metadata_uri.clone())
+	verify {
+		assert_eq!(MetadataOf::<T>::get(kitty_id).map(|m| m.metadata_uri.into_inner()), Some(metadata_uri));
+	}
+
+	on_initialize {
+		let b in 0 .. T::MaxBirthsPerBlock::get();
+		let due_at: T::BlockNumber = 2u32.into();
+		let mut births = Vec::new();
+		for i in 0 .. b {
+			let breeder: T::AccountId = account("breeder", i, 0);
+			let matron_id: T::KittyIndex = (i + 1).into();
+			Pregnancies::<T>::insert(matron_id, Pregnancy {
+				sire: matron_id,
+				breeder: breeder.clone(),
+				dna: [0u8; 16],
+				deposit: 0u32.into(),
+				due_at,
+			});
+			PendingBirths::<T>::insert(&breeder, 1);
+			births.push(matron_id);
+		}
+		BirthSchedule::<T>::insert(due_at, BoundedVec::try_from(births).map_err(|_| "too many births")?);
+	}: {
+		Kitties::<T>::on_initialize(due_at);
+	}
+	verify {
+		assert!(BirthSchedule::<T>::get(due_at).is_empty());
+		assert_eq!(Pregnancies::<T>::iter().count() as u32, 0);
+	}
+}
+
+impl_benchmark_test_suite!(
+	Kitties,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
@@ -0,0 +1,171 @@
+//! Kitty的基因模型：DNA布局、显隐性遗传、突变以及性状解码。
+
+use codec::{Encode, Decode};
+use sp_runtime::RuntimeDebug;
+
+//DNA布局（共16字节）：
+//  [0..2]  毛色基因 (显性等位基因, 隐性等位基因)
+//  [2..4]  花纹基因 (显性等位基因, 隐性等位基因)
+//  [4..6]  眼睛基因 (显性等位基因, 隐性等位基因)
+//  [6..8]  代数，u16小端序
+//  [8]     冷却等级
+//  [9..16] 未表达的随机基因
+pub const COLOR: usize = 0;
+pub const PATTERN: usize = 2;
+pub const EYES: usize = 4;
+pub const GENERATION: usize = 6;
+pub const COOLDOWN: usize = 8;
+pub const ENTROPY: usize = 9;
+
+//父母遗传隐性等位基因的概率：64/256 = 25%
+pub const RECESSIVE_CHANCE: u8 = 64;
+//等位基因突变的概率：8/256 ≈ 3%
+pub const MUTATION_RATE: u8 = 8;
+//冷却等级上限
+pub const MAX_COOLDOWN_INDEX: u8 = 13;
+
+pub type Dna = [u8; 16];
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Color {
+    Black,
+    White,
+    Orange,
+    Gray,
+    Cinnamon,
+    Cream,
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Pattern {
+    Solid,
+    Tabby,
+    Spotted,
+    Calico,
+    Tortoiseshell,
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Eyes {
+    Blue,
+    Green,
+    Amber,
+    Copper,
+}
+
+//解码后的Kitty性状，只包含显性等位基因表达出来的部分
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct KittyTraits {
+    pub color: Color,
+    pub pattern: Pattern,
+    pub eyes: Eyes,
+    pub generation: u16,
+    pub cooldown_index: u8,
+}
+
+impl From<u8> for Color {
+    fn from(allele: u8) -> Self {
+        match allele % 6 {
+            0 => Color::Black,
+            1 => Color::White,
+            2 => Color::Orange,
+            3 => Color::Gray,
+            4 => Color::Cinnamon,
+            _ => Color::Cream,
+        }
+    }
+}
+
+impl From<u8> for Pattern {
+    fn from(allele: u8) -> Self {
+        match allele % 5 {
+            0 => Pattern::Solid,
+            1 => Pattern::Tabby,
+            2 => Pattern::Spotted,
+            3 => Pattern::Calico,
+            _ => Pattern::Tortoiseshell,
+        }
+    }
+}
+
+impl From<u8> for Eyes {
+    fn from(allele: u8) -> Self {
+        match allele % 4 {
+            0 => Eyes::Blue,
+            1 => Eyes::Green,
+            2 => Eyes::Amber,
+            _ => Eyes::Copper,
+        }
+    }
+}
+
+pub fn generation(dna: &Dna) -> u16 {
+    u16::from_le_bytes([dna[GENERATION], dna[GENERATION + 1]])
+}
+
+pub fn cooldown_index(dna: &Dna) -> u8 {
+    dna[COOLDOWN]
+}
+
+//解码DNA中表达出来的性状
+pub fn decode_traits(dna: &Dna) -> KittyTraits {
+    KittyTraits {
+        color: dna[COLOR].into(),
+        pattern: dna[PATTERN].into(),
+        eyes: dna[EYES].into(),
+        generation: generation(dna),
+        cooldown_index: cooldown_index(dna),
+    }
+}
+
+//新创建的初代Kitty：基因随机，代数和冷却等级为0
+pub fn random_genome(seed: Dna) -> Dna {
+    let mut dna = seed;
+    dna[GENERATION..ENTROPY].copy_from_slice(&[0u8; 3]);
+    dna
+}
+
+//父母各遗传一个等位基因，按概率发生突变
+//selector每个基因使用4字节：[父方选择, 母方选择, 显隐顺序, 突变]
+fn inherit(a: (u8, u8), b: (u8, u8), selector: &[u8]) -> (u8, u8) {
+    let from_a = if selector[0] < RECESSIVE_CHANCE { a.1 } else { a.0 };
+    let from_b = if selector[1] < RECESSIVE_CHANCE { b.1 } else { b.0 };
+
+    let (mut dominant, recessive) = if selector[2] & 1 == 0 {
+        (from_a, from_b)
+    } else {
+        (from_b, from_a)
+    };
+
+    if selector[3] < MUTATION_RATE {
+        dominant = dominant.wrapping_add(selector[3] + 1);
+    }
+
+    (dominant, recessive)
+}
+
+//繁殖后代的DNA：代数为父母中较大者加一，冷却等级随代数增长
+pub fn breed_genome(dna_1: &Dna, dna_2: &Dna, selector: &Dna) -> Dna {
+    let mut dna = [0u8; 16];
+
+    for (gene, offset) in [COLOR, PATTERN, EYES].iter().enumerate() {
+        let i = *offset;
+        let (dominant, recessive) = inherit(
+            (dna_1[i], dna_1[i + 1]),
+            (dna_2[i], dna_2[i + 1]),
+            &selector[gene * 4..gene * 4 + 4],
+        );
+        dna[i] = dominant;
+        dna[i + 1] = recessive;
+    }
+
+    let child_generation = generation(dna_1).max(generation(dna_2)).saturating_add(1);
+    dna[GENERATION..COOLDOWN].copy_from_slice(&child_generation.to_le_bytes());
+    dna[COOLDOWN] = (child_generation / 2).min(MAX_COOLDOWN_INDEX as u16) as u8;
+
+    for (i, byte) in dna.iter_mut().enumerate().skip(ENTROPY) {
+        *byte = (selector[i] & dna_1[i]) | (!selector[i] & dna_2[i]);
+    }
+
+    dna
+}
@@ -0,0 +1,62 @@
+//! 为Kitty实现FRAME的nonfungibles接口，其他模块可以通过trait约束查询和转移Kitty。
+//! Kitty只有一个类别，ClassId为()。
+
+use super::*;
+use codec::Encode;
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    traits::tokens::nonfungibles::{Inspect, Mutate, Transfer},
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type InstanceId = T::KittyIndex;
+    type ClassId = ();
+
+    fn owner(_class: &Self::ClassId, instance: &Self::InstanceId) -> Option<T::AccountId> {
+        Owner::<T>::get(instance)
+    }
+
+    //支持的属性：dna为16字节基因，generation为SCALE编码的代数，name和metadata_uri为设置过的名字和元数据URI
+    fn attribute(_class: &Self::ClassId, instance: &Self::InstanceId, key: &[u8]) -> Option<Vec<u8>> {
+        let kitty = Kitties::<T>::get(instance)?;
+        let metadata = MetadataOf::<T>::get(instance);
+        match key {
+            b"dna" => Some(kitty.0.to_vec()),
+            b"generation" => Some(genome::generation(&kitty.0).encode()),
+            b"name" => metadata.map(|m| m.name.into_inner()).filter(|name| !name.is_empty()),
+            b"metadata_uri" => metadata.map(|m| m.metadata_uri.into_inner()).filter(|uri| !uri.is_empty()),
+            _ => None,
+        }
+    }
+
+    //拍卖中和怀孕中的Kitty不能转移
+    fn can_transfer(_class: &Self::ClassId, instance: &Self::InstanceId) -> bool {
+        Owner::<T>::get(instance).is_some() &&
+            Pallet::<T>::ensure_lock_allows(*instance, &[KittyLock::Listed, KittyLock::Siring]).is_ok()
+    }
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+    fn transfer(_class: &Self::ClassId, instance: &Self::InstanceId, destination: &T::AccountId) -> DispatchResult {
+        let owner = Owner::<T>::get(instance).ok_or(Error::<T>::InvalidKittyIndex)?;
+
+        Pallet::<T>::do_transfer(owner, destination.clone(), *instance)
+    }
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+    //只能铸造下一个可用的KittyIndex，铸造者需要质押KittyReserveMoney
+    fn mint_into(_class: &Self::ClassId, instance: &Self::InstanceId, who: &T::AccountId) -> DispatchResult {
+        ensure!(*instance == Pallet::<T>::next_kitty_id()?, Error::<T>::InvalidKittyIndex);
+
+        Pallet::<T>::do_create(who).map(|_| ())
+    }
+
+    fn burn_from(_class: &Self::ClassId, instance: &Self::InstanceId) -> DispatchResult {
+        let owner = Owner::<T>::get(instance).ok_or(Error::<T>::InvalidKittyIndex)?;
+
+        Pallet::<T>::do_burn(owner, *instance).map(|_| ())
+    }
+}
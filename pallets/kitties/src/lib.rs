@@ -7,24 +7,150 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub mod genome;
+
+pub mod migrations;
+
+mod impl_nonfungibles;
+
 #[frame_support::pallet]
 pub mod pallet{
     use frame_support::{
-        dispatch::DispatchResult, 
+        dispatch::{DispatchError, DispatchResult},
         pallet_prelude::*,
-        traits::{Randomness, Currency, ReservableCurrency, ExistenceRequirement}
+        storage::bounded_vec::BoundedVec,
+        traits::{
+            Randomness, Currency, ReservableCurrency, ExistenceRequirement, BalanceStatus,
+            OnUnbalanced, WithdrawReasons,
+        },
+        storage::{with_transaction, TransactionOutcome},
+        transactional,
     };
     use frame_system::pallet_prelude::*;
     use codec::{Encode, Decode};
     use sp_io::hashing::blake2_128;
     use sp_runtime::{
-        traits::{AtLeast32BitUnsigned,Bounded}
+        traits::{AtLeast32BitUnsigned, CheckedAdd, Saturating, Zero},
+        Perbill, SaturatedConversion,
     };
+    use crate::weights::WeightInfo;
+    use crate::genome::{self, KittyTraits};
+    use sp_std::{collections::btree_set::BTreeSet, convert::TryFrom, vec, vec::Vec};
 
     #[derive(Encode, Decode)]
     pub struct Kitty(pub [u8;16]);
 
-	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    //Kitty的血统：父母、代数和出生区块
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+    pub struct KittyLineage<KittyIndex, BlockNumber> {
+        pub parents: Option<(KittyIndex, KittyIndex)>,
+        pub generation: u16,
+        pub born_at: BlockNumber,
+    }
+
+    pub type KittyLineageOf<T> = KittyLineage<<T as Config>::KittyIndex, <T as frame_system::Config>::BlockNumber>;
+
+    //怀孕信息：父方、繁殖者、后代基因、预产区块和繁殖时为后代质押的金额
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+    pub struct Pregnancy<AccountId, KittyIndex, Balance, BlockNumber> {
+        pub sire: KittyIndex,
+        pub breeder: AccountId,
+        pub dna: [u8; 16],
+        pub deposit: Balance,
+        pub due_at: BlockNumber,
+    }
+
+    pub type PregnancyOf<T> = Pregnancy<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::KittyIndex,
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+    >;
+
+    //Kitty的锁定状态，处于某个状态时与之冲突的操作会被拒绝
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum KittyLock {
+        //通过sell_kitty出售中
+        Listed,
+        //拍卖中
+        Auctioning,
+        //对外出租繁殖中
+        Siring,
+        //怀孕中
+        Gestating,
+    }
+
+    //Kitty的供应统计：累计铸造、累计销毁、现存数量以及各代现存数量
+    #[derive(Encode, Decode, Clone, Default, PartialEq, RuntimeDebug)]
+    pub struct SupplyStats {
+        pub minted: u64,
+        pub burned: u64,
+        pub live: u64,
+        pub per_generation: Vec<(u16, u64)>,
+    }
+
+    //拍卖方式
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+    pub enum AuctionKind<Balance> {
+        //英式拍卖：从起拍价开始加价，出价者的资金被质押，结束时最高出价者成交
+        English { starting_price: Balance },
+        //荷式拍卖：价格从起始价线性降低到最低价，第一个出价者按当前价格成交
+        Dutch { start_price: Balance, end_price: Balance },
+    }
+
+    //拍卖信息：卖家、拍卖方式、开始和结束区块，以及英式拍卖的最高出价
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+    pub struct Auction<AccountId, Balance, BlockNumber> {
+        pub seller: AccountId,
+        pub kind: AuctionKind<Balance>,
+        pub start: BlockNumber,
+        pub end: BlockNumber,
+        pub best_bid: Option<(AccountId, Balance)>,
+    }
+
+    //对Kitty的报价：报价者、质押的金额和过期区块
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+    pub struct Offer<AccountId, Balance, BlockNumber> {
+        pub bidder: AccountId,
+        pub amount: Balance,
+        pub expires_at: BlockNumber,
+    }
+
+    pub type OfferOf<T> = Offer<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+    >;
+
+    //Kitty的名字和链下元数据URI，以及按字节质押的金额
+    #[derive(Encode, Decode, Clone, Default, PartialEq, RuntimeDebug)]
+    pub struct KittyMetadata<Name, Uri, Balance> {
+        pub name: Name,
+        pub metadata_uri: Uri,
+        pub deposit: Balance,
+    }
+
+    pub type KittyNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+    pub type KittyMetadataOf<T> = KittyMetadata<
+        KittyNameOf<T>,
+        BoundedVec<u8, <T as Config>::MaxMetadataLength>,
+        BalanceOf<T>,
+    >;
+
+    pub type AuctionOf<T> = Auction<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+    >;
+
+	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
     //定义配置接口
     #[pallet::config]
@@ -34,6 +160,45 @@ pub mod pallet{
         type KittyIndex: Parameter + Member + AtLeast32BitUnsigned + Default + Copy;
         type KittyReserveMoney: Get<BalanceOf<Self>>;
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+        //每个账户最多拥有的Kitty数量
+        type MaxKittiesOwned: Get<u32>;
+        //查询血统时向上追溯的最大代数
+        type MaxAncestryDepth: Get<u32>;
+        //繁殖后的基础冷却区块数，实际冷却时间随冷却等级翻倍
+        type BaseCooldown: Get<Self::BlockNumber>;
+        //怀孕区块数，为0时后代立即出生
+        type GestationPeriod: Get<Self::BlockNumber>;
+        //同一区块最多出生的Kitty数量
+        type MaxBirthsPerBlock: Get<u32>;
+        //拍卖的最长区块数
+        type MaxAuctionDuration: Get<Self::BlockNumber>;
+        //同一区块最多结束的拍卖数量
+        type MaxAuctionsPerBlock: Get<u32>;
+        //英式拍卖结束前这么多区块内出价，结束区块延后到出价区块加上该值
+        type AntiSnipeDuration: Get<Self::BlockNumber>;
+        //英式拍卖每次加价的最小幅度
+        type MinBidIncrement: Get<BalanceOf<Self>>;
+        //市场手续费比例
+        type MarketplaceFee: Get<Perbill>;
+        //每次成交付给Kitty创作者的版税比例
+        type CreatorRoyalty: Get<Perbill>;
+        //市场手续费的去向，例如国库账户
+        type OnMarketplaceFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+        //每个Kitty最多同时存在的报价数量
+        type MaxOffersPerKitty: Get<u32>;
+        //每个账户最多同时存在的报价数量
+        type MaxOffersPerBidder: Get<u32>;
+        //报价的最长有效区块数
+        type MaxOfferDuration: Get<Self::BlockNumber>;
+        //名字的最大字节数
+        type MaxNameLength: Get<u32>;
+        //元数据URI的最大字节数
+        type MaxMetadataLength: Get<u32>;
+        //名字和元数据URI每字节的质押金额
+        type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+        //为true时不同Kitty不能使用相同的名字
+        type UniqueNames: Get<bool>;
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -46,15 +211,84 @@ pub mod pallet{
     pub enum Event<T: Config>{
         KittyCreate(T::AccountId, T::KittyIndex),
         Kittytransfer(T::AccountId, T::AccountId, T::KittyIndex),
-        //KittyBuy(who, owner, kitty_id, kitty_price)
         KittyOnSale(T::AccountId, T::KittyIndex, BalanceOf<T>),
-        KittyBuy(T::AccountId, T::KittyIndex, BalanceOf<T>),
+        //KittyBuy(buyer, kitty_id, price, marketplace_fee, royalty)
+        KittyBuy(T::AccountId, T::KittyIndex, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+        //KittySaleCancelled(owner, kitty_id)
+        KittySaleCancelled(T::AccountId, T::KittyIndex),
+        //SiringApproved(owner, sire_id, 被授权的账户，None表示取消授权)
+        SiringApproved(T::AccountId, T::KittyIndex, Option<T::AccountId>),
+        //KittyPregnant(breeder, matron_id, sire_id, due_at)
+        KittyPregnant(T::AccountId, T::KittyIndex, T::KittyIndex, T::BlockNumber),
+        //KittyBorn(owner, kitty_id, matron_id, sire_id)
+        KittyBorn(T::AccountId, T::KittyIndex, T::KittyIndex, T::KittyIndex),
+        //SiringOffered(owner, sire_id, stud_fee)
+        SiringOffered(T::AccountId, T::KittyIndex, BalanceOf<T>),
+        //SiringOfferCancelled(owner, sire_id)
+        SiringOfferCancelled(T::AccountId, T::KittyIndex),
+        //SiredWithFee(breeder, matron_id, sire_id, stud_fee)
+        SiredWithFee(T::AccountId, T::KittyIndex, T::KittyIndex, BalanceOf<T>),
+        //AuctionCreated(seller, kitty_id, end)
+        AuctionCreated(T::AccountId, T::KittyIndex, T::BlockNumber),
+        //BidPlaced(bidder, kitty_id, amount)
+        BidPlaced(T::AccountId, T::KittyIndex, BalanceOf<T>),
+        //AuctionExtended(kitty_id, new_end)
+        AuctionExtended(T::KittyIndex, T::BlockNumber),
+        //AuctionSettled(seller, winner, kitty_id, price, marketplace_fee, royalty)
+        AuctionSettled(T::AccountId, T::AccountId, T::KittyIndex, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+        //AuctionClosed(seller, kitty_id)，拍卖结束但没有成交
+        AuctionClosed(T::AccountId, T::KittyIndex),
+        //AuctionCancelled(seller, kitty_id)
+        AuctionCancelled(T::AccountId, T::KittyIndex),
+        //OfferMade(bidder, kitty_id, amount, expires_at)
+        OfferMade(T::AccountId, T::KittyIndex, BalanceOf<T>, T::BlockNumber),
+        //OfferWithdrawn(bidder, kitty_id)
+        OfferWithdrawn(T::AccountId, T::KittyIndex),
+        //OfferEvicted(bidder, kitty_id, 退还的金额)，报价已满时过期或最低的报价被移除
+        OfferEvicted(T::AccountId, T::KittyIndex, BalanceOf<T>),
+        //OfferAccepted(owner, bidder, kitty_id, amount, marketplace_fee, royalty)
+        OfferAccepted(T::AccountId, T::AccountId, T::KittyIndex, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+        //KittyBurned(owner, kitty_id, 退还的质押)
+        KittyBurned(T::AccountId, T::KittyIndex, BalanceOf<T>),
+        //Approval(owner, kitty_id, 被授权的账户，None表示取消授权)
+        Approval(T::AccountId, T::KittyIndex, Option<T::AccountId>),
+        //ApprovalForAll(owner, operator, approved)
+        ApprovalForAll(T::AccountId, T::AccountId, bool),
+        //KittyNamed(owner, kitty_id, name)，名字为空表示清除
+        KittyNamed(T::AccountId, T::KittyIndex, Vec<u8>),
+        //KittyMetadataSet(owner, kitty_id, metadata_uri)，URI为空表示清除
+        KittyMetadataSet(T::AccountId, T::KittyIndex, Vec<u8>),
+    }
+
+    #[pallet::type_value]
+    pub fn DefaultNextKittyId<T: Config>() -> T::KittyIndex {
+        1u32.into()
     }
 
-    //Kitty数量
+    //下一个分配的KittyIndex，从1开始，只增不减，与现存数量无关
+    #[pallet::storage]
+    #[pallet::getter(fn next_id)]
+    pub type NextKittyId<T: Config> = StorageValue<_, T::KittyIndex, ValueQuery, DefaultNextKittyId<T>>;
+
+    //现存的Kitty数量，销毁时减少
+    #[pallet::storage]
+    #[pallet::getter(fn total_supply)]
+    pub type TotalSupply<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    //累计铸造的Kitty数量，包括创建、繁殖出生和创世预置
+    #[pallet::storage]
+    #[pallet::getter(fn kitties_minted)]
+    pub type KittiesMinted<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    //累计销毁的Kitty数量
     #[pallet::storage]
-    #[pallet::getter(fn kitties_count)]
-    pub type KittiesCount<T: Config> = StorageValue<_, T::KittyIndex>;
+    #[pallet::getter(fn kitties_burned)]
+    pub type KittiesBurned<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    //各代现存的Kitty数量
+    #[pallet::storage]
+    #[pallet::getter(fn generation_supply)]
+    pub type GenerationSupply<T: Config> = StorageMap<_, Twox64Concat, u16, u64, ValueQuery>;
 
     //Kitty实体
     #[pallet::storage]
@@ -66,6 +300,110 @@ pub mod pallet{
     #[pallet::getter(fn kitty_owner)]
     pub type Owner<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, Option<T::AccountId>, ValueQuery>;
 
+    //账户拥有的Kitty列表
+    #[pallet::storage]
+    #[pallet::getter(fn owned_kitties)]
+    pub type OwnedKitties<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<T::KittyIndex, T::MaxKittiesOwned>, ValueQuery>;
+
+    //Kitty血统
+    #[pallet::storage]
+    #[pallet::getter(fn lineage)]
+    pub type Lineages<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, KittyLineageOf<T>>;
+
+    //被授权使用该Kitty作为父方繁殖的账户
+    #[pallet::storage]
+    #[pallet::getter(fn siring_approval)]
+    pub type SiringApprovals<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, T::AccountId>;
+
+    //对外出租繁殖的父方Kitty及其配种费
+    #[pallet::storage]
+    #[pallet::getter(fn siring_offer)]
+    pub type SiringOffers<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, BalanceOf<T>>;
+
+    //Kitty冷却结束的区块，之前不能繁殖
+    #[pallet::storage]
+    #[pallet::getter(fn ready_at)]
+    pub type ReadyAt<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, T::BlockNumber>;
+
+    //怀孕中的母方Kitty
+    #[pallet::storage]
+    #[pallet::getter(fn pregnancy)]
+    pub type Pregnancies<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, PregnancyOf<T>>;
+
+    //每个区块将要分娩的母方Kitty
+    #[pallet::storage]
+    #[pallet::getter(fn birth_schedule)]
+    pub type BirthSchedule<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::KittyIndex, T::MaxBirthsPerBlock>, ValueQuery>;
+
+    //账户尚未出生的后代数量，计入拥有上限
+    #[pallet::storage]
+    #[pallet::getter(fn pending_births)]
+    pub type PendingBirths<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    //进行中的拍卖
+    #[pallet::storage]
+    #[pallet::getter(fn auctions)]
+    pub type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, AuctionOf<T>>;
+
+    //每个区块结束的拍卖
+    #[pallet::storage]
+    #[pallet::getter(fn auction_endings)]
+    pub type AuctionEndings<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::KittyIndex, T::MaxAuctionsPerBlock>, ValueQuery>;
+
+    //每个Kitty收到的报价
+    #[pallet::storage]
+    #[pallet::getter(fn offers)]
+    pub type Offers<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, BoundedVec<OfferOf<T>, T::MaxOffersPerKitty>, ValueQuery>;
+
+    //账户报价过的Kitty
+    #[pallet::storage]
+    #[pallet::getter(fn offers_of)]
+    pub type OffersOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<T::KittyIndex, T::MaxOffersPerBidder>, ValueQuery>;
+
+    //被授权转移该Kitty的账户，Kitty转移后清除
+    #[pallet::storage]
+    #[pallet::getter(fn approved)]
+    pub type Approvals<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, T::AccountId>;
+
+    //拥有者授权的操作员，可以转移和授权拥有者的所有Kitty
+    #[pallet::storage]
+    #[pallet::getter(fn is_approved_for_all)]
+    pub type OperatorApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    //Kitty的名字和元数据
+    #[pallet::storage]
+    #[pallet::getter(fn metadata)]
+    pub type MetadataOf<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, KittyMetadataOf<T>>;
+
+    //名字索引：名字 => 使用该名字的Kitty
+    #[pallet::storage]
+    pub type NameIndex<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        KittyNameOf<T>,
+        Blake2_128Concat,
+        T::KittyIndex,
+        (),
+    >;
+
+    //Kitty的创作者，即创建或繁殖出该Kitty的账户
+    #[pallet::storage]
+    #[pallet::getter(fn kitty_creator)]
+    pub type KittyCreators<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, T::AccountId>;
+
+    //Kitty的质押金额，由当前拥有者质押，随Kitty转移，销毁时退还
+    #[pallet::storage]
+    #[pallet::getter(fn kitty_deposit)]
+    pub type KittyDeposits<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, BalanceOf<T>, ValueQuery>;
+
     //Kitty售价
     #[pallet::storage]
     #[pallet::getter(fn kitties_price)]
@@ -86,58 +424,156 @@ pub mod pallet{
         //Kitty处于非卖状态
         KittyIsNotOnSale,
         //金额不够
-        MoneyIsNotEnough
+        MoneyIsNotEnough,
+        //账户拥有的Kitty数量达到上限
+        ExceedMaxKittiesOwned,
+        //父方Kitty没有授权调用者繁殖
+        SiringNotApproved,
+        //父母是兄弟姐妹
+        SiblingBreeding,
+        //父母之间是亲子关系
+        ParentChildBreeding,
+        //Kitty处于繁殖冷却中
+        KittyInCooldown,
+        //Kitty正在怀孕
+        KittyIsPregnant,
+        //该区块出生的Kitty数量达到上限
+        TooManyBirths,
+        //父方Kitty没有对外出租繁殖
+        SireNotOffered,
+        //Kitty正在拍卖
+        KittyInAuction,
+        //Kitty处于出售状态
+        KittyIsOnSale,
+        //拍卖不存在
+        AuctionNotExist,
+        //拍卖区块数为0或超过上限
+        InvalidAuctionDuration,
+        //荷式拍卖的起始价低于最低价
+        InvalidAuctionPrice,
+        //出价低于起拍价或最小加价
+        BidTooLow,
+        //拍卖已结束
+        AuctionExpired,
+        //英式拍卖已有出价，不能取消
+        AuctionHasBids,
+        //该区块结束的拍卖数量达到上限
+        TooManyAuctions,
+        //报价不存在
+        OfferNotExist,
+        //报价已过期
+        OfferExpired,
+        //报价金额为0
+        OfferTooLow,
+        //报价有效区块数为0或超过上限
+        InvalidOfferDuration,
+        //Kitty或账户的报价数量达到上限
+        TooManyOffers,
+        //调用者不是拥有者、被授权账户或操作员
+        NotApproved,
+        //不能授权给自己
+        ApproveToSelf,
+        //名字超过最大长度
+        NameTooLong,
+        //元数据URI超过最大长度
+        MetadataTooLong,
+        //名字已被其他Kitty使用
+        NameTaken,
+        //Kitty正在对外出租繁殖
+        KittyIsSiring,
+        //新拥有者账户不存在，无法接收随Kitty转移的质押
+        NewOwnerNotExist,
+    }
+
+    //创世区块中预先创建的Kitty：(拥有者, DNA)，拥有者需要质押KittyReserveMoney
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub kitties: Vec<(T::AccountId, [u8; 16])>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { kitties: Vec::new() }
+        }
     }
 
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for (owner, dna) in self.kitties.iter() {
+                let deposit = T::KittyReserveMoney::get();
+                T::Currency::reserve(owner, deposit).expect("genesis kitty owner should be able to reserve the deposit");
+                Pallet::<T>::mint_kitty(owner.clone(), Kitty(*dna), None, deposit)
+                    .expect("genesis kitties should not overflow KittyIndex or exceed MaxKittiesOwned");
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            crate::migrations::migrate_kitty_deposits::<T>()
+                .saturating_add(crate::migrations::migrate_owned_kitties::<T>())
+                .saturating_add(crate::migrations::migrate_to_next_kitty_id::<T>())
+        }
+
+        //怀孕期满的Kitty在区块开始时出生
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let births = BirthSchedule::<T>::take(n);
+            let count = births.len() as u32;
+
+            for matron_id in births {
+                //只有KittyIndex溢出时才会失败，此时放弃这次出生
+                let _ = Self::give_birth(matron_id);
+            }
+
+            //预留on_finalize结算拍卖的权重
+            let auctions = AuctionEndings::<T>::decode_len(n).unwrap_or(0) as u32;
+
+            T::WeightInfo::on_initialize(count).saturating_add(T::WeightInfo::on_finalize(auctions))
+        }
+
+        //到期的拍卖在区块结束时结算
+        fn on_finalize(n: T::BlockNumber) {
+            for kitty_id in AuctionEndings::<T>::take(n) {
+                if let Some(auction) = Auctions::<T>::take(kitty_id) {
+                    Self::settle_auction(kitty_id, auction);
+                }
+            }
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T>{
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::create())]
         //创建Kitty
         pub fn create(origin: OriginFor<T>) -> DispatchResult{
             let who = ensure_signed(origin)?;
-        
-            let kitty_id = match Self::kitties_count() {
-                Some(id) => {
-                    ensure!(id != T::KittyIndex::max_value(), Error::<T>::KittiesCountOverflow);
-                    id.into()
-                },
-                None => {
-                    1u32.into()
-                }
-            };
-            //质押代币,
-            T::Currency::reserve(&who, T::KittyReserveMoney::get()).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
-
-            let dna = Self::random_value(&who);
-            
-            Self::insert_kitty(who.clone(), kitty_id, Kitty(dna));
-
-            Self::deposit_event(Event::KittyCreate(who, kitty_id));
 
-            Ok(())
+            Self::do_create(&who).map(|_| ())
         }
 
         //转移Kitty所有权
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::transfer())]
         pub fn transfer(origin: OriginFor<T>, new_owner: T::AccountId, kitty_id: T::KittyIndex) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             ensure!(Some(who.clone()) == Owner::<T>::get(kitty_id), Error::<T>::NotOwner);
 
-            Self::transfer_kitty(who, new_owner, kitty_id);
-    
-            Ok(())
+            Self::do_transfer(who, new_owner, kitty_id)
         }
 
         //出售Kitty
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::sell_kitty())]
         pub fn  sell_kitty(origin: OriginFor<T>, kitty_id: T::KittyIndex, kitty_price: BalanceOf<T>) -> DispatchResult{
             let who = ensure_signed(origin)?;
 
             let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
 
             ensure!(owner == who.clone(), Error::<T>::NotOwner);
+            //已出售时可以更新售价
+            Self::ensure_lock_allows(kitty_id, &[KittyLock::Listed])?;
 
             KittiesPrice::<T>::insert(kitty_id, Some(kitty_price));
             
@@ -146,14 +582,33 @@ pub mod pallet{
             Ok(())
         }
 
+        //取消出售Kitty
+        #[pallet::weight(T::WeightInfo::cancel_sale())]
+        pub fn cancel_sale(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+
+            ensure!(owner == who, Error::<T>::NotOwner);
+            ensure!(Self::kitties_price(kitty_id).is_some(), Error::<T>::KittyIsNotOnSale);
+
+            KittiesPrice::<T>::remove(kitty_id);
+
+            Self::deposit_event(Event::KittySaleCancelled(who, kitty_id));
+
+            Ok(())
+        }
+
         //购买Kitty
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::buy_kitty())]
+        #[transactional]
         pub fn buy_kitty(origin: OriginFor<T>, kitty_id: T::KittyIndex, pay_value: BalanceOf<T>) -> DispatchResult{
             let who = ensure_signed(origin)?;
 
             let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
 
             ensure!(owner != who.clone(), Error::<T>::InvalidKittyBuyer);
+            Self::ensure_lock_allows(kitty_id, &[KittyLock::Listed])?;
 
             //判断购买的Kitty是否存在
             let kitty_price = Self::kitties_price(kitty_id).ok_or(Error::<T>::KittyIsNotOnSale)?;
@@ -161,62 +616,527 @@ pub mod pallet{
             //判断金额
             ensure!(pay_value >= kitty_price, Error::<T>::MoneyIsNotEnough);
 
-            //转账
-			T::Currency::transfer(&who, &owner, kitty_price, ExistenceRequirement::KeepAlive).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+            Self::ensure_can_own(&who)?;
+
+            //转账，扣除市场手续费和创作者版税
+            let (fee, royalty) = Self::pay_from_free(&who, &owner, kitty_id, kitty_price)?;
 
-            T::Currency::unreserve(&owner, T::KittyReserveMoney::get());
             //删除Kitty，防止重复购买
             KittiesPrice::<T>::remove(kitty_id);
 
             //转移Kitty所有权
-            Self::transfer_kitty(owner, who.clone(), kitty_id);
+            Self::transfer_kitty(owner, who.clone(), kitty_id)?;
 
-            Self::deposit_event(Event::KittyBuy(who, kitty_id, kitty_price));
+            Self::deposit_event(Event::KittyBuy(who, kitty_id, kitty_price, fee, royalty));
 
             Ok(())
         }
 
         //繁殖Kitty
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::breed())]
+        #[transactional]
         pub fn breed(origin: OriginFor<T>, kitty_id_1: T::KittyIndex, kitty_id_2: T::KittyIndex) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             ensure!(kitty_id_1 != kitty_id_2, Error::<T>::SameParentIndex);
 
             let kitty1 = Self::kitties(kitty_id_1).ok_or(Error::<T>::InvalidKittyIndex)?;
-            let kitty2 = Self::kitties(kitty_id_1).ok_or(Error::<T>::InvalidKittyIndex)?;
+            let kitty2 = Self::kitties(kitty_id_2).ok_or(Error::<T>::InvalidKittyIndex)?;
+
+            //母方必须属于调用者，父方属于调用者或已授权调用者
+            ensure!(Some(who.clone()) == Self::kitty_owner(kitty_id_1), Error::<T>::NotOwner);
+            let via_approval = Some(who.clone()) != Self::kitty_owner(kitty_id_2);
+            if via_approval {
+                ensure!(Some(who.clone()) == Self::siring_approval(kitty_id_2), Error::<T>::SiringNotApproved);
+            }
+
+            Self::do_breed(&who, kitty_id_1, kitty1, kitty_id_2, kitty2)?;
+
+            //授权只能使用一次
+            if via_approval {
+                SiringApprovals::<T>::remove(kitty_id_2);
+            }
+
+            Ok(())
+        }
+
+        //授权其他账户使用自己的Kitty作为父方繁殖，who为None时取消授权
+        #[pallet::weight(T::WeightInfo::approve_siring())]
+        pub fn approve_siring(origin: OriginFor<T>, sire_id: T::KittyIndex, who: Option<T::AccountId>) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(Some(owner.clone()) == Self::kitty_owner(sire_id), Error::<T>::NotOwner);
+
+            match who.clone() {
+                Some(account) => SiringApprovals::<T>::insert(sire_id, account),
+                None => SiringApprovals::<T>::remove(sire_id),
+            }
+
+            Self::deposit_event(Event::SiringApproved(owner, sire_id, who));
+
+            Ok(())
+        }
+
+        //以配种费对外出租Kitty作为父方繁殖，重复调用会更新配种费
+        #[pallet::weight(T::WeightInfo::offer_siring())]
+        pub fn offer_siring(origin: OriginFor<T>, sire_id: T::KittyIndex, stud_fee: BalanceOf<T>) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(Some(owner.clone()) == Self::kitty_owner(sire_id), Error::<T>::NotOwner);
+            //已出租时可以更新配种费
+            Self::ensure_lock_allows(sire_id, &[KittyLock::Siring])?;
+
+            SiringOffers::<T>::insert(sire_id, stud_fee);
+
+            Self::deposit_event(Event::SiringOffered(owner, sire_id, stud_fee));
+
+            Ok(())
+        }
+
+        //取消出租繁殖
+        #[pallet::weight(T::WeightInfo::cancel_siring_offer())]
+        pub fn cancel_siring_offer(origin: OriginFor<T>, sire_id: T::KittyIndex) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(Some(owner.clone()) == Self::kitty_owner(sire_id), Error::<T>::NotOwner);
+            ensure!(SiringOffers::<T>::contains_key(sire_id), Error::<T>::SireNotOffered);
+
+            SiringOffers::<T>::remove(sire_id);
+
+            Self::deposit_event(Event::SiringOfferCancelled(owner, sire_id));
+
+            Ok(())
+        }
+
+        //支付配种费，使用出租的父方Kitty繁殖，后代归支付者，父方仍归原拥有者并进入冷却
+        #[pallet::weight(T::WeightInfo::breed_with_sire())]
+        #[transactional]
+        pub fn breed_with_sire(
+            origin: OriginFor<T>,
+            matron_id: T::KittyIndex,
+            sire_id: T::KittyIndex,
+            pay_value: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(matron_id != sire_id, Error::<T>::SameParentIndex);
+
+            let matron = Self::kitties(matron_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+            let sire = Self::kitties(sire_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+
+            ensure!(Some(who.clone()) == Self::kitty_owner(matron_id), Error::<T>::NotOwner);
+
+            let sire_owner = Self::kitty_owner(sire_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+            ensure!(sire_owner != who, Error::<T>::InvalidKittyBuyer);
+
+            let stud_fee = Self::siring_offer(sire_id).ok_or(Error::<T>::SireNotOffered)?;
+            ensure!(pay_value >= stud_fee, Error::<T>::MoneyIsNotEnough);
+
+            Self::do_breed(&who, matron_id, matron, sire_id, sire)?;
+
+            T::Currency::transfer(&who, &sire_owner, stud_fee, ExistenceRequirement::KeepAlive).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+
+            Self::deposit_event(Event::SiredWithFee(who, matron_id, sire_id, stud_fee));
+
+            Ok(())
+        }
+
+        //发起拍卖，duration个区块后在on_finalize中结算
+        #[pallet::weight(T::WeightInfo::create_auction())]
+        pub fn create_auction(
+            origin: OriginFor<T>,
+            kitty_id: T::KittyIndex,
+            kind: AuctionKind<BalanceOf<T>>,
+            duration: T::BlockNumber,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Some(who.clone()) == Self::kitty_owner(kitty_id), Error::<T>::NotOwner);
+            Self::ensure_lock_allows(kitty_id, &[])?;
+            ensure!(
+                !duration.is_zero() && duration <= T::MaxAuctionDuration::get(),
+                Error::<T>::InvalidAuctionDuration
+            );
+            if let AuctionKind::Dutch { start_price, end_price } = kind {
+                ensure!(start_price >= end_price, Error::<T>::InvalidAuctionPrice);
+            }
+
+            let start = <frame_system::Pallet<T>>::block_number();
+            let end = start.saturating_add(duration);
+
+            AuctionEndings::<T>::try_mutate(end, |endings| {
+                endings.try_push(kitty_id).map_err(|_| Error::<T>::TooManyAuctions)
+            })?;
+            Auctions::<T>::insert(kitty_id, Auction { seller: who.clone(), kind, start, end, best_bid: None });
 
-            let kitty_id = match Self::kitties_count() {
-                Some(id) => {
-                    ensure!(id != T::KittyIndex::max_value(), Error::<T>::KittiesCountOverflow);
-                    id.into()
+            Self::deposit_event(Event::AuctionCreated(who, kitty_id, end));
+
+            Ok(())
+        }
+
+        //出价：英式拍卖质押出价并退还上一个出价者，荷式拍卖按当前价格立即成交
+        #[pallet::weight(T::WeightInfo::bid())]
+        #[transactional]
+        pub fn bid(origin: OriginFor<T>, kitty_id: T::KittyIndex, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut auction = Self::auctions(kitty_id).ok_or(Error::<T>::AuctionNotExist)?;
+            ensure!(auction.seller != who, Error::<T>::InvalidKittyBuyer);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now < auction.end, Error::<T>::AuctionExpired);
+
+            Self::ensure_can_own(&who)?;
+
+            match auction.kind {
+                AuctionKind::English { starting_price } => {
+                    let min_bid = match &auction.best_bid {
+                        Some((_, best)) => best.saturating_add(T::MinBidIncrement::get()),
+                        None => starting_price,
+                    };
+                    ensure!(amount >= min_bid, Error::<T>::BidTooLow);
+
+                    //退还上一个出价者的质押
+                    if let Some((bidder, best)) = auction.best_bid.take() {
+                        T::Currency::unreserve(&bidder, best);
+                    }
+                    Self::reserve_keep_alive(&who, amount)?;
+                    auction.best_bid = Some((who.clone(), amount));
+
+                    //防止最后时刻出价：在结束前AntiSnipeDuration个区块内出价时延长拍卖
+                    let extended_end = now.saturating_add(T::AntiSnipeDuration::get());
+                    if extended_end > auction.end {
+                        AuctionEndings::<T>::mutate(auction.end, |endings| endings.retain(|id| *id != kitty_id));
+                        AuctionEndings::<T>::try_mutate(extended_end, |endings| {
+                            endings.try_push(kitty_id).map_err(|_| Error::<T>::TooManyAuctions)
+                        })?;
+                        auction.end = extended_end;
+                        Self::deposit_event(Event::AuctionExtended(kitty_id, extended_end));
+                    }
+
+                    Auctions::<T>::insert(kitty_id, auction);
+
+                    Self::deposit_event(Event::BidPlaced(who, kitty_id, amount));
                 },
-                None => {
-                    1u32.into()
-                }
-            };
-            
-            let dna_1 = kitty1.0;
-            let dna_2 = kitty2.0;
+                AuctionKind::Dutch { .. } => {
+                    let price = Self::dutch_price(&auction, now);
+                    ensure!(amount >= price, Error::<T>::BidTooLow);
+
+                    let (fee, royalty) = Self::pay_from_free(&who, &auction.seller, kitty_id, price)?;
 
-            let selector = Self::random_value(&who);
+                    Auctions::<T>::remove(kitty_id);
+                    AuctionEndings::<T>::mutate(auction.end, |endings| endings.retain(|id| *id != kitty_id));
 
-            let mut new_dna = [0u8; 16];
+                    Self::transfer_kitty(auction.seller.clone(), who.clone(), kitty_id)?;
 
-            for i in 0..dna_1.len() {
-                new_dna[i] = (selector[i] & dna_1[i]) | (!selector[i] & dna_2[i]);
+                    Self::deposit_event(Event::AuctionSettled(auction.seller, who, kitty_id, price, fee, royalty));
+                },
             }
 
-            Self::insert_kitty(who.clone(), kitty_id, Kitty(new_dna));
+            Ok(())
+        }
+
+        //取消拍卖，英式拍卖只能在没有出价时取消
+        #[pallet::weight(T::WeightInfo::cancel_auction())]
+        pub fn cancel_auction(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let auction = Self::auctions(kitty_id).ok_or(Error::<T>::AuctionNotExist)?;
+            ensure!(auction.seller == who, Error::<T>::NotOwner);
+            ensure!(auction.best_bid.is_none(), Error::<T>::AuctionHasBids);
+
+            Auctions::<T>::remove(kitty_id);
+            AuctionEndings::<T>::mutate(auction.end, |endings| endings.retain(|id| *id != kitty_id));
 
-            Self::deposit_event(Event::KittyCreate(who, kitty_id));
+            Self::deposit_event(Event::AuctionCancelled(who, kitty_id));
 
             Ok(())
         }
+
+        //对任意Kitty报价，报价金额被质押直到报价被接受或撤回，重复报价会替换之前的报价
+        //报价金额不能低于存在性押金；报价已满时移除一个过期的报价，没有过期的报价时移除低于新报价的最低报价
+        #[pallet::weight(T::WeightInfo::make_offer())]
+        #[transactional]
+        pub fn make_offer(
+            origin: OriginFor<T>,
+            kitty_id: T::KittyIndex,
+            amount: BalanceOf<T>,
+            duration: T::BlockNumber,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+            ensure!(owner != who, Error::<T>::InvalidKittyBuyer);
+            ensure!(
+                !amount.is_zero() && amount >= T::Currency::minimum_balance(),
+                Error::<T>::OfferTooLow
+            );
+            ensure!(
+                !duration.is_zero() && duration <= T::MaxOfferDuration::get(),
+                Error::<T>::InvalidOfferDuration
+            );
+
+            let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+            let offer = Offer { bidder: who.clone(), amount, expires_at };
+
+            //替换之前的报价时先退还之前的质押
+            if let Some(previous) = Self::take_offer(kitty_id, &who) {
+                T::Currency::unreserve(&who, previous.amount);
+            }
+            Self::evict_offer_if_full(kitty_id, amount)?;
+            Offers::<T>::try_mutate(kitty_id, |offers| {
+                offers.try_push(offer).map_err(|_| Error::<T>::TooManyOffers)
+            })?;
+            OffersOf::<T>::try_mutate(&who, |kitties| {
+                kitties.try_push(kitty_id).map_err(|_| Error::<T>::TooManyOffers)
+            })?;
+
+            Self::reserve_keep_alive(&who, amount)?;
+
+            Self::deposit_event(Event::OfferMade(who, kitty_id, amount, expires_at));
+
+            Ok(())
+        }
+
+        //撤回报价并退还质押，过期的报价也需要撤回
+        #[pallet::weight(T::WeightInfo::withdraw_offer())]
+        pub fn withdraw_offer(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let offer = Self::take_offer(kitty_id, &who).ok_or(Error::<T>::OfferNotExist)?;
+            T::Currency::unreserve(&who, offer.amount);
+
+            Self::deposit_event(Event::OfferWithdrawn(who, kitty_id));
+
+            Ok(())
+        }
+
+        //Kitty拥有者接受未过期的报价，质押的报价金额转给拥有者并转移Kitty
+        #[pallet::weight(T::WeightInfo::accept_offer())]
+        #[transactional]
+        pub fn accept_offer(origin: OriginFor<T>, kitty_id: T::KittyIndex, bidder: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Some(who.clone()) == Self::kitty_owner(kitty_id), Error::<T>::NotOwner);
+            ensure!(who != bidder, Error::<T>::InvalidKittyBuyer);
+            Self::ensure_lock_allows(kitty_id, &[KittyLock::Listed, KittyLock::Siring])?;
+
+            let offer = Self::take_offer(kitty_id, &bidder).ok_or(Error::<T>::OfferNotExist)?;
+            ensure!(<frame_system::Pallet<T>>::block_number() < offer.expires_at, Error::<T>::OfferExpired);
+
+            Self::ensure_can_own(&bidder)?;
+
+            let (fee, royalty) = Self::pay_from_reserved(&bidder, &who, kitty_id, offer.amount);
+
+            Self::transfer_kitty(who.clone(), bidder.clone(), kitty_id)?;
+
+            Self::deposit_event(Event::OfferAccepted(who, bidder, kitty_id, offer.amount, fee, royalty));
+
+            Ok(())
+        }
+
+        //授权delegate转移Kitty，拥有者或其操作员可以调用，delegate为None时取消授权
+        #[pallet::weight(T::WeightInfo::approve())]
+        pub fn approve(origin: OriginFor<T>, kitty_id: T::KittyIndex, delegate: Option<T::AccountId>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+            ensure!(owner == who || Self::is_approved_for_all(&owner, &who), Error::<T>::NotApproved);
+
+            match delegate.clone() {
+                Some(account) => {
+                    ensure!(account != owner, Error::<T>::ApproveToSelf);
+                    Approvals::<T>::insert(kitty_id, account);
+                },
+                None => Approvals::<T>::remove(kitty_id),
+            }
+
+            Self::deposit_event(Event::Approval(owner, kitty_id, delegate));
+
+            Ok(())
+        }
+
+        //授权或取消操作员转移和授权调用者的所有Kitty
+        #[pallet::weight(T::WeightInfo::set_approval_for_all())]
+        pub fn set_approval_for_all(origin: OriginFor<T>, operator: T::AccountId, approved: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(operator != who, Error::<T>::ApproveToSelf);
+
+            if approved {
+                OperatorApprovals::<T>::insert(&who, &operator, true);
+            } else {
+                OperatorApprovals::<T>::remove(&who, &operator);
+            }
+
+            Self::deposit_event(Event::ApprovalForAll(who, operator, approved));
+
+            Ok(())
+        }
+
+        //拥有者、被授权账户或操作员把from的Kitty转给to
+        #[pallet::weight(T::WeightInfo::transfer_from())]
+        pub fn transfer_from(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            kitty_id: T::KittyIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Some(from.clone()) == Self::kitty_owner(kitty_id), Error::<T>::NotOwner);
+            ensure!(
+                who == from ||
+                Some(who.clone()) == Self::approved(kitty_id) ||
+                Self::is_approved_for_all(&from, &who),
+                Error::<T>::NotApproved
+            );
+
+            Self::do_transfer(from, to, kitty_id)
+        }
+
+        //设置Kitty的名字，按字节质押，名字为空时清除名字并退还质押
+        #[pallet::weight(T::WeightInfo::set_name(name.len() as u32))]
+        #[transactional]
+        pub fn set_name(origin: OriginFor<T>, kitty_id: T::KittyIndex, name: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Some(who.clone()) == Self::kitty_owner(kitty_id), Error::<T>::NotOwner);
+
+            let name = KittyNameOf::<T>::try_from(name).map_err(|_| Error::<T>::NameTooLong)?;
+            if T::UniqueNames::get() && !name.is_empty() {
+                ensure!(
+                    !NameIndex::<T>::iter_prefix(&name).any(|(id, _)| id != kitty_id),
+                    Error::<T>::NameTaken
+                );
+            }
+
+            let mut metadata = Self::metadata(kitty_id).unwrap_or_default();
+            if !metadata.name.is_empty() {
+                NameIndex::<T>::remove(&metadata.name, kitty_id);
+            }
+            if !name.is_empty() {
+                NameIndex::<T>::insert(&name, kitty_id, ());
+            }
+            metadata.name = name.clone();
+
+            Self::update_metadata(&who, kitty_id, metadata)?;
+
+            Self::deposit_event(Event::KittyNamed(who, kitty_id, name.into_inner()));
+
+            Ok(())
+        }
+
+        //设置Kitty的链下元数据URI，按字节质押，URI为空时清除并退还质押
+        #[pallet::weight(T::WeightInfo::set_metadata(metadata_uri.len() as u32))]
+        #[transactional]
+        pub fn set_metadata(origin: OriginFor<T>, kitty_id: T::KittyIndex, metadata_uri: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Some(who.clone()) == Self::kitty_owner(kitty_id), Error::<T>::NotOwner);
+
+            let metadata_uri = BoundedVec::<u8, T::MaxMetadataLength>::try_from(metadata_uri)
+                .map_err(|_| Error::<T>::MetadataTooLong)?;
+
+            let mut metadata = Self::metadata(kitty_id).unwrap_or_default();
+            metadata.metadata_uri = metadata_uri.clone();
+
+            Self::update_metadata(&who, kitty_id, metadata)?;
+
+            Self::deposit_event(Event::KittyMetadataSet(who, kitty_id, metadata_uri.into_inner()));
+
+            Ok(())
+        }
+
+        //销毁Kitty：删除Kitty及其出售、出租和报价信息，退还Kitty的质押和所有报价者的质押
+        //按报价数量上限预估权重，执行后按实际退还的报价数量返还多余的权重
+        #[pallet::weight(T::WeightInfo::burn(T::MaxOffersPerKitty::get()))]
+        pub fn burn(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Some(who.clone()) == Self::kitty_owner(kitty_id), Error::<T>::NotOwner);
+
+            let offers = Self::do_burn(who, kitty_id)?;
+
+            Ok(Some(T::WeightInfo::burn(offers)).into())
+        }
     }
 
     //提取公共代码
     impl<T: Config> Pallet<T>{
+        //为账户创建一个随机基因的初代Kitty，返回分配的kitty_id
+        pub(crate) fn do_create(who: &T::AccountId) -> Result<T::KittyIndex, DispatchError> {
+            Self::next_kitty_id()?;
+            Self::ensure_can_own(who)?;
+
+            //质押代币,
+            let deposit = T::KittyReserveMoney::get();
+            T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+
+            let dna = genome::random_genome(Self::random_value(who));
+            
+            let kitty_id = Self::mint_kitty(who.clone(), Kitty(dna), None, deposit)?;
+
+            Self::deposit_event(Event::KittyCreate(who.clone(), kitty_id));
+
+            Ok(kitty_id)
+        }
+
+        //拥有者把Kitty转给新拥有者，拍卖中和怀孕中的Kitty不能转移，出售和出租繁殖在转移时取消
+        pub(crate) fn do_transfer(owner: T::AccountId, new_owner: T::AccountId, kitty_id: T::KittyIndex) -> DispatchResult {
+            Self::ensure_lock_allows(kitty_id, &[KittyLock::Listed, KittyLock::Siring])?;
+
+            Self::ensure_can_own(&new_owner)?;
+
+            //质押以质押状态转给新拥有者，不能转入还不存在的账户
+            ensure!(
+                Self::kitty_deposit(kitty_id).is_zero() || !T::Currency::total_balance(&new_owner).is_zero(),
+                Error::<T>::NewOwnerNotExist
+            );
+
+            Self::transfer_kitty(owner, new_owner, kitty_id)
+        }
+
+        //拥有者销毁Kitty，返回退还的报价数量
+        pub(crate) fn do_burn(who: T::AccountId, kitty_id: T::KittyIndex) -> Result<u32, DispatchError> {
+            Self::ensure_lock_allows(kitty_id, &[KittyLock::Listed, KittyLock::Siring])?;
+
+            OwnedKitties::<T>::mutate(&who, |kitties| kitties.retain(|id| *id != kitty_id));
+            Kitties::<T>::remove(kitty_id);
+            Owner::<T>::remove(kitty_id);
+            KittiesPrice::<T>::remove(kitty_id);
+            SiringApprovals::<T>::remove(kitty_id);
+            SiringOffers::<T>::remove(kitty_id);
+            Approvals::<T>::remove(kitty_id);
+            ReadyAt::<T>::remove(kitty_id);
+            KittyCreators::<T>::remove(kitty_id);
+            if let Some(metadata) = MetadataOf::<T>::take(kitty_id) {
+                NameIndex::<T>::remove(&metadata.name, kitty_id);
+            }
+
+            let offers = Offers::<T>::take(kitty_id);
+            let offer_count = offers.len() as u32;
+            for offer in offers {
+                OffersOf::<T>::mutate(&offer.bidder, |kitties| kitties.retain(|id| *id != kitty_id));
+                T::Currency::unreserve(&offer.bidder, offer.amount);
+            }
+
+            let deposit = KittyDeposits::<T>::take(kitty_id);
+            T::Currency::unreserve(&who, deposit);
+
+            TotalSupply::<T>::mutate(|supply| *supply = supply.saturating_sub(1));
+            KittiesBurned::<T>::mutate(|burned| *burned = burned.saturating_add(1));
+            if let Some(lineage) = Lineages::<T>::get(kitty_id) {
+                GenerationSupply::<T>::mutate_exists(lineage.generation, |supply| {
+                    *supply = supply.map(|s| s.saturating_sub(1)).filter(|s| *s > 0);
+                });
+            }
+
+            Self::deposit_event(Event::KittyBurned(who, kitty_id, deposit));
+
+            Ok(offer_count)
+        }
+
         fn random_value(sender: &T::AccountId) -> [u8; 16] {
             let payload = (
                 T::Randomness::random_seed(),
@@ -228,18 +1148,475 @@ pub mod pallet{
         }
 
 
-        fn transfer_kitty(owner: T::AccountId, new_owner: T::AccountId, kitty_id: T::KittyIndex){
+        //解码Kitty的性状，供runtime API使用
+        pub fn decode_traits(kitty_id: T::KittyIndex) -> Option<KittyTraits> {
+            Self::kitties(kitty_id).map(|kitty| genome::decode_traits(&kitty.0))
+        }
+
+        pub fn parents_of(kitty_id: T::KittyIndex) -> Option<(T::KittyIndex, T::KittyIndex)> {
+            Self::lineage(kitty_id).and_then(|lineage| lineage.parents)
+        }
+
+        //禁止兄弟姐妹之间以及亲子之间繁殖
+        fn ensure_not_inbreeding(kitty_id_1: T::KittyIndex, kitty_id_2: T::KittyIndex) -> DispatchResult {
+            let parents_1 = Self::parents_of(kitty_id_1);
+            let parents_2 = Self::parents_of(kitty_id_2);
+
+            let is_parent = |parents: Option<(T::KittyIndex, T::KittyIndex)>, id: T::KittyIndex| {
+                parents.map_or(false, |(p1, p2)| p1 == id || p2 == id)
+            };
+            ensure!(
+                !is_parent(parents_1, kitty_id_2) && !is_parent(parents_2, kitty_id_1),
+                Error::<T>::ParentChildBreeding
+            );
+
+            if let Some((a1, a2)) = parents_1 {
+                ensure!(!is_parent(parents_2, a1) && !is_parent(parents_2, a2), Error::<T>::SiblingBreeding);
+            }
+
+            Ok(())
+        }
+
+        //从kitty_id开始向上追溯血统，返回每个Kitty及其父母，最多追溯MaxAncestryDepth代
+        pub fn ancestry(kitty_id: T::KittyIndex, depth: u32) -> Vec<(T::KittyIndex, Option<(T::KittyIndex, T::KittyIndex)>)> {
+            let mut ancestry = Vec::new();
+            if Self::kitties(kitty_id).is_none() {
+                return ancestry;
+            }
+
+            let depth = depth.min(T::MaxAncestryDepth::get());
+            let mut visited = BTreeSet::new();
+            visited.insert(kitty_id);
+            let mut current = vec![kitty_id];
+
+            for level in 0..=depth {
+                let mut next = Vec::new();
+                for id in current {
+                    let parents = Self::parents_of(id);
+                    ancestry.push((id, parents));
+                    if level == depth {
+                        continue;
+                    }
+                    if let Some((p1, p2)) = parents {
+                        for parent in [p1, p2].iter() {
+                            if visited.insert(*parent) {
+                                next.push(*parent);
+                            }
+                        }
+                    }
+                }
+                if next.is_empty() {
+                    break;
+                }
+                current = next;
+            }
+
+            ancestry
+        }
+
+        //下一个可用的KittyIndex，只检查不分配；分配后计数器会溢出时返回错误
+        pub(crate) fn next_kitty_id() -> Result<T::KittyIndex, DispatchError> {
+            let id = Self::next_id();
+            id.checked_add(&1u32.into()).ok_or(Error::<T>::KittiesCountOverflow)?;
+            Ok(id)
+        }
+
+        //分配下一个KittyIndex并推进计数器
+        fn allocate_kitty_id() -> Result<T::KittyIndex, DispatchError> {
+            NextKittyId::<T>::try_mutate(|next| {
+                let id = *next;
+                *next = id.checked_add(&1u32.into()).ok_or(Error::<T>::KittiesCountOverflow)?;
+                Ok(id)
+            })
+        }
+
+        //Kitty的供应统计，供runtime API使用
+        pub fn supply_stats() -> SupplyStats {
+            let mut per_generation: Vec<(u16, u64)> = GenerationSupply::<T>::iter().collect();
+            per_generation.sort_unstable();
+
+            SupplyStats {
+                minted: Self::kitties_minted(),
+                burned: Self::kitties_burned(),
+                live: Self::total_supply(),
+                per_generation,
+            }
+        }
+
+        //繁殖后代：检查冷却和怀孕状态，父母进入冷却，后代立即出生或等待怀孕期满
+        fn do_breed(
+            breeder: &T::AccountId,
+            matron_id: T::KittyIndex,
+            matron: Kitty,
+            sire_id: T::KittyIndex,
+            sire: Kitty,
+        ) -> DispatchResult {
+            Self::ensure_not_inbreeding(matron_id, sire_id)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            for id in [matron_id, sire_id].iter() {
+                ensure!(Self::ready_at(id).map_or(true, |at| now >= at), Error::<T>::KittyInCooldown);
+                //出售或拍卖中的Kitty不能繁殖，对外出租繁殖的Kitty可以
+                Self::ensure_lock_allows(*id, &[KittyLock::Siring])?;
+            }
+
+            Self::next_kitty_id()?;
+            Self::ensure_can_own(breeder)?;
+
+            //繁殖者为后代质押代币，出生后随后代转移
+            let deposit = T::KittyReserveMoney::get();
+            T::Currency::reserve(breeder, deposit).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+
+            //按显隐性遗传和突变规则生成后代基因
+            let selector = Self::random_value(breeder);
+            let dna = genome::breed_genome(&matron.0, &sire.0, &selector);
+
+            ReadyAt::<T>::insert(matron_id, now.saturating_add(Self::cooldown_of(&matron)));
+            ReadyAt::<T>::insert(sire_id, now.saturating_add(Self::cooldown_of(&sire)));
+
+            let due_at = now.saturating_add(T::GestationPeriod::get());
+            Pregnancies::<T>::insert(matron_id, Pregnancy { sire: sire_id, breeder: breeder.clone(), dna, deposit, due_at });
+            PendingBirths::<T>::mutate(breeder, |count| *count += 1);
+
+            if due_at == now {
+                return Self::give_birth(matron_id);
+            }
+
+            BirthSchedule::<T>::try_mutate(due_at, |births| {
+                births.try_push(matron_id).map_err(|_| Error::<T>::TooManyBirths)
+            })?;
+
+            Self::deposit_event(Event::KittyPregnant(breeder.clone(), matron_id, sire_id, due_at));
+
+            Ok(())
+        }
+
+        //怀孕期满，后代出生并归繁殖者所有
+        fn give_birth(matron_id: T::KittyIndex) -> DispatchResult {
+            let pregnancy = Pregnancies::<T>::take(matron_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+            PendingBirths::<T>::mutate(&pregnancy.breeder, |count| *count = count.saturating_sub(1));
+
+            //无法分配KittyIndex时放弃这次出生，退还质押
+            Self::next_kitty_id().map_err(|e| {
+                T::Currency::unreserve(&pregnancy.breeder, pregnancy.deposit);
+                e
+            })?;
+            let kitty_id = Self::mint_kitty(
+                pregnancy.breeder.clone(),
+                Kitty(pregnancy.dna),
+                Some((matron_id, pregnancy.sire)),
+                pregnancy.deposit,
+            )?;
+
+            Self::deposit_event(Event::KittyBorn(pregnancy.breeder, kitty_id, matron_id, pregnancy.sire));
+
+            Ok(())
+        }
+
+        //保存Kitty的名字和元数据，按字节数补足或退还拥有者的质押，质押计入Kitty的质押随Kitty转移
+        fn update_metadata(owner: &T::AccountId, kitty_id: T::KittyIndex, mut metadata: KittyMetadataOf<T>) -> DispatchResult {
+            let old_deposit = metadata.deposit;
+            let bytes = (metadata.name.len() + metadata.metadata_uri.len()) as u32;
+            let new_deposit = T::MetadataDepositPerByte::get().saturating_mul(bytes.into());
+
+            if new_deposit > old_deposit {
+                T::Currency::reserve(owner, new_deposit - old_deposit).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+            } else {
+                T::Currency::unreserve(owner, old_deposit - new_deposit);
+            }
+            KittyDeposits::<T>::mutate(kitty_id, |deposit| {
+                *deposit = deposit.saturating_add(new_deposit).saturating_sub(old_deposit)
+            });
+
+            if bytes == 0 {
+                MetadataOf::<T>::remove(kitty_id);
+            } else {
+                metadata.deposit = new_deposit;
+                MetadataOf::<T>::insert(kitty_id, metadata);
+            }
+
+            Ok(())
+        }
+
+        //移除账户对Kitty的报价，不退还质押
+        fn take_offer(kitty_id: T::KittyIndex, bidder: &T::AccountId) -> Option<OfferOf<T>> {
+            let mut offers = Offers::<T>::get(kitty_id);
+            let offer = offers.iter().find(|offer| offer.bidder == *bidder).cloned()?;
+            offers.retain(|offer| offer.bidder != *bidder);
+
+            if offers.is_empty() {
+                Offers::<T>::remove(kitty_id);
+            } else {
+                Offers::<T>::insert(kitty_id, offers);
+            }
+            OffersOf::<T>::mutate(bidder, |kitties| kitties.retain(|id| *id != kitty_id));
+
+            Some(offer)
+        }
+
+        //质押出价或报价，可用余额需要保留存在性押金，否则用质押的金额支付后账户会被回收，无法接收随Kitty转移的质押
+        fn reserve_keep_alive(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            ensure!(
+                T::Currency::free_balance(who) >= amount.saturating_add(T::Currency::minimum_balance()),
+                Error::<T>::MoneyIsNotEnough
+            );
+            T::Currency::reserve(who, amount).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+            Ok(())
+        }
+
+        //Kitty的报价已满时腾出一个位置：优先移除已过期的报价，否则移除低于amount的最低报价，并退还被移除报价的质押
+        fn evict_offer_if_full(kitty_id: T::KittyIndex, amount: BalanceOf<T>) -> DispatchResult {
+            let offers = Offers::<T>::get(kitty_id);
+            if (offers.len() as u32) < T::MaxOffersPerKitty::get() {
+                return Ok(());
+            }
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let bidder = offers.iter()
+                .find(|offer| now >= offer.expires_at)
+                .or_else(|| offers.iter().min_by_key(|offer| offer.amount).filter(|offer| offer.amount < amount))
+                .map(|offer| offer.bidder.clone())
+                .ok_or(Error::<T>::TooManyOffers)?;
+
+            let offer = Self::take_offer(kitty_id, &bidder).ok_or(Error::<T>::OfferNotExist)?;
+            T::Currency::unreserve(&bidder, offer.amount);
+
+            Self::deposit_event(Event::OfferEvicted(bidder, kitty_id, offer.amount));
+
+            Ok(())
+        }
+
+        //成交价中的市场手续费和创作者版税，卖家就是创作者时不收版税
+        fn sale_fees(
+            kitty_id: T::KittyIndex,
+            seller: &T::AccountId,
+            price: BalanceOf<T>,
+        ) -> (BalanceOf<T>, Option<(T::AccountId, BalanceOf<T>)>) {
+            let fee = T::MarketplaceFee::get() * price;
+            let royalty = Self::kitty_creator(kitty_id)
+                .filter(|creator| creator != seller)
+                .map(|creator| (creator, T::CreatorRoyalty::get() * price));
+            (fee, royalty)
+        }
+
+        //买家用可用余额支付成交价：手续费交给OnMarketplaceFee，版税付给创作者，其余付给卖家
+        fn pay_from_free(
+            buyer: &T::AccountId,
+            seller: &T::AccountId,
+            kitty_id: T::KittyIndex,
+            price: BalanceOf<T>,
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+            let (fee, royalty) = Self::sale_fees(kitty_id, seller, price);
+
+            let imbalance = T::Currency::withdraw(buyer, fee, WithdrawReasons::TRANSFER, ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+            T::OnMarketplaceFee::on_unbalanced(imbalance);
+
+            //版税无法转给创作者时（例如低于存在性押金）归卖家所有
+            let mut royalty_paid = Zero::zero();
+            if let Some((creator, amount)) = royalty {
+                if T::Currency::transfer(buyer, &creator, amount, ExistenceRequirement::KeepAlive).is_ok() {
+                    royalty_paid = amount;
+                }
+            }
+
+            let proceeds = price.saturating_sub(fee).saturating_sub(royalty_paid);
+            T::Currency::transfer(buyer, seller, proceeds, ExistenceRequirement::KeepAlive).map_err(|_| Error::<T>::MoneyIsNotEnough)?;
+
+            Ok((fee, royalty_paid))
+        }
+
+        //用买家质押的出价支付成交价，分配方式同pay_from_free
+        fn pay_from_reserved(
+            buyer: &T::AccountId,
+            seller: &T::AccountId,
+            kitty_id: T::KittyIndex,
+            price: BalanceOf<T>,
+        ) -> (BalanceOf<T>, BalanceOf<T>) {
+            let (fee, royalty) = Self::sale_fees(kitty_id, seller, price);
+
+            let (imbalance, _) = T::Currency::slash_reserved(buyer, fee);
+            T::OnMarketplaceFee::on_unbalanced(imbalance);
+
+            let mut royalty_paid = Zero::zero();
+            if let Some((creator, amount)) = royalty {
+                if T::Currency::repatriate_reserved(buyer, &creator, amount, BalanceStatus::Free).is_ok() {
+                    royalty_paid = amount;
+                }
+            }
+
+            let proceeds = price.saturating_sub(fee).saturating_sub(royalty_paid);
+            let _ = T::Currency::repatriate_reserved(buyer, seller, proceeds, BalanceStatus::Free);
+
+            (fee, royalty_paid)
+        }
+
+        //荷式拍卖的当前价格：从起始价按经过的区块数线性降低到最低价
+        pub fn dutch_price(auction: &AuctionOf<T>, now: T::BlockNumber) -> BalanceOf<T> {
+            match auction.kind {
+                AuctionKind::Dutch { start_price, end_price } => {
+                    let duration: u32 = auction.end.saturating_sub(auction.start).saturated_into();
+                    if duration == 0 {
+                        return end_price;
+                    }
+                    let elapsed: u32 = now.saturating_sub(auction.start).saturated_into::<u32>().min(duration);
+                    let drop = start_price.saturating_sub(end_price).saturating_mul(elapsed.into()) / duration.into();
+                    start_price.saturating_sub(drop)
+                },
+                AuctionKind::English { starting_price } => starting_price,
+            }
+        }
+
+        //结算到期的拍卖：最高出价者的质押转给卖家并转移Kitty，买家无法再拥有Kitty或转移失败时退还质押
+        fn settle_auction(kitty_id: T::KittyIndex, auction: AuctionOf<T>) {
+            match auction.best_bid {
+                Some((winner, price)) if Self::ensure_can_own(&winner).is_ok() => {
+                    //on_finalize中没有外层事务：支付和转移在同一个事务中进行，转移失败（例如买家支付后账户被回收）时全部回滚
+                    let settled = with_transaction(|| {
+                        let (fee, royalty) = Self::pay_from_reserved(&winner, &auction.seller, kitty_id, price);
+                        match Self::transfer_kitty(auction.seller.clone(), winner.clone(), kitty_id) {
+                            Ok(()) => TransactionOutcome::Commit(Some((fee, royalty))),
+                            Err(_) => TransactionOutcome::Rollback(None),
+                        }
+                    });
+
+                    match settled {
+                        Some((fee, royalty)) => {
+                            Self::deposit_event(Event::AuctionSettled(auction.seller, winner, kitty_id, price, fee, royalty));
+                        },
+                        None => {
+                            T::Currency::unreserve(&winner, price);
+                            Self::deposit_event(Event::AuctionClosed(auction.seller, kitty_id));
+                        },
+                    }
+                },
+                Some((bidder, price)) => {
+                    T::Currency::unreserve(&bidder, price);
+                    Self::deposit_event(Event::AuctionClosed(auction.seller, kitty_id));
+                },
+                None => {
+                    Self::deposit_event(Event::AuctionClosed(auction.seller, kitty_id));
+                },
+            }
+        }
+
+        //Kitty当前所处的锁定状态
+        pub fn locks_of(kitty_id: T::KittyIndex) -> Vec<KittyLock> {
+            let mut locks = Vec::new();
+            if Auctions::<T>::contains_key(kitty_id) {
+                locks.push(KittyLock::Auctioning);
+            }
+            if Pregnancies::<T>::contains_key(kitty_id) {
+                locks.push(KittyLock::Gestating);
+            }
+            if Self::kitties_price(kitty_id).is_some() {
+                locks.push(KittyLock::Listed);
+            }
+            if SiringOffers::<T>::contains_key(kitty_id) {
+                locks.push(KittyLock::Siring);
+            }
+            locks
+        }
+
+        //检查Kitty只处于allowed中的锁定状态，否则返回对应的错误
+        pub(crate) fn ensure_lock_allows(kitty_id: T::KittyIndex, allowed: &[KittyLock]) -> DispatchResult {
+            match Self::locks_of(kitty_id).into_iter().find(|lock| !allowed.contains(lock)) {
+                Some(KittyLock::Auctioning) => Err(Error::<T>::KittyInAuction.into()),
+                Some(KittyLock::Gestating) => Err(Error::<T>::KittyIsPregnant.into()),
+                Some(KittyLock::Listed) => Err(Error::<T>::KittyIsOnSale.into()),
+                Some(KittyLock::Siring) => Err(Error::<T>::KittyIsSiring.into()),
+                None => Ok(()),
+            }
+        }
+
+        //冷却区块数：基础冷却乘以2的冷却等级次方
+        fn cooldown_of(kitty: &Kitty) -> T::BlockNumber {
+            let index = genome::cooldown_index(&kitty.0).min(genome::MAX_COOLDOWN_INDEX);
+            T::BaseCooldown::get().saturating_mul((1u32 << index).into())
+        }
+
+        //检查账户拥有的Kitty数量（包括尚未出生的后代）是否未达到上限
+        fn ensure_can_own(who: &T::AccountId) -> DispatchResult {
+            let owned = Self::owned_kitties(who).len() as u32;
+            ensure!(
+                owned.saturating_add(Self::pending_births(who)) < T::MaxKittiesOwned::get(),
+                Error::<T>::ExceedMaxKittiesOwned
+            );
+            Ok(())
+        }
+
+        //转移Kitty：先转移质押再更新索引，质押无法转移时不修改任何索引
+        fn transfer_kitty(owner: T::AccountId, new_owner: T::AccountId, kitty_id: T::KittyIndex) -> DispatchResult {
+            //质押随Kitty转移到新拥有者，仍处于质押状态
+            let deposit = Self::kitty_deposit(kitty_id);
+            let unmoved = T::Currency::repatriate_reserved(&owner, &new_owner, deposit, BalanceStatus::Reserved)?;
+            KittyDeposits::<T>::insert(kitty_id, deposit.saturating_sub(unmoved));
+
+            OwnedKitties::<T>::mutate(&owner, |kitties| kitties.retain(|id| *id != kitty_id));
+            OwnedKitties::<T>::try_mutate(&new_owner, |kitties| {
+                kitties.try_push(kitty_id).map_err(|_| Error::<T>::ExceedMaxKittiesOwned)
+            })?;
+
             Owner::<T>::insert(kitty_id, Some(new_owner.clone()));
+            SiringApprovals::<T>::remove(kitty_id);
+            SiringOffers::<T>::remove(kitty_id);
+            Approvals::<T>::remove(kitty_id);
+
+            //转移时取消之前拥有者的出售
+            if KittiesPrice::<T>::take(kitty_id).is_some() {
+                Self::deposit_event(Event::KittySaleCancelled(owner.clone(), kitty_id));
+            }
 
             Self::deposit_event(Event::Kittytransfer(owner, new_owner, kitty_id));
+
+            Ok(())
+        }
+
+        //分配KittyIndex并保存新Kitty，创建、繁殖出生和创世预置共用，返回分配的kitty_id
+        fn mint_kitty(
+            owner: T::AccountId,
+            kitty: Kitty,
+            parents: Option<(T::KittyIndex, T::KittyIndex)>,
+            deposit: BalanceOf<T>,
+        ) -> Result<T::KittyIndex, DispatchError> {
+            let kitty_id = Self::allocate_kitty_id()?;
+
+            Self::insert_kitty(owner, kitty_id, kitty, parents, deposit)?;
+
+            Ok(kitty_id)
         }
 
-        fn insert_kitty(owner: T::AccountId, kitty_id: T::KittyIndex, kitty: Kitty){
+        fn insert_kitty(
+            owner: T::AccountId,
+            kitty_id: T::KittyIndex,
+            kitty: Kitty,
+            parents: Option<(T::KittyIndex, T::KittyIndex)>,
+            deposit: BalanceOf<T>,
+        ) -> DispatchResult {
+            OwnedKitties::<T>::try_mutate(&owner, |kitties| {
+                kitties.try_push(kitty_id).map_err(|_| Error::<T>::ExceedMaxKittiesOwned)
+            })?;
+
+            let generation = genome::generation(&kitty.0);
+            Lineages::<T>::insert(kitty_id, KittyLineage {
+                parents,
+                generation,
+                born_at: <frame_system::Pallet<T>>::block_number(),
+            });
+
             Kitties::<T>::insert(kitty_id, Some(kitty));
 
+            KittyCreators::<T>::insert(kitty_id, owner.clone());
+
+            KittyDeposits::<T>::insert(kitty_id, deposit);
+
             Owner::<T>::insert(kitty_id, Some(owner));
 
-            KittiesCount::<T>::put(kitty_id + 1u32.into());
+            TotalSupply::<T>::mutate(|supply| *supply = supply.saturating_add(1));
+            KittiesMinted::<T>::mutate(|minted| *minted = minted.saturating_add(1));
+            GenerationSupply::<T>::mutate(generation, |supply| *supply = supply.saturating_add(1));
+
+            Ok(())
         }
 
        
@@ -0,0 +1,145 @@
+//! Storage migrations for the kitties pallet.
+
+use crate::{
+    genome, BalanceOf, Config, GenerationSupply, Kitties, KittiesBurned, KittiesMinted, Kitty, KittyCreators,
+    KittyDeposits, KittyLineage, Lineages, NextKittyId, OwnedKitties, Owner, Pallet, TotalSupply,
+};
+use frame_support::{
+    storage::migration::take_storage_value,
+    traits::{Get, GetPalletVersion, PalletInfoAccess, PalletVersion, ReservableCurrency},
+    weights::Weight,
+};
+use sp_runtime::{traits::{Saturating, Zero}, SaturatedConversion};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+//为3.1.0之前创建的Kitty补记质押：拥有者当时质押的KittyReserveMoney仍然处于质押状态，
+//按拥有者实际质押的金额依次记入其Kitty，不足时只记入剩余部分，已迁移过的链不做任何修改
+//旧版转移不转移质押，质押留在了之前的拥有者上，超出账户现有Kitty质押的部分退还给账户
+//3.1.0之前的链上只有本模块使用T::Currency的质押
+pub fn migrate_kitty_deposits<T: Config>() -> Weight {
+    let version = <Pallet<T> as GetPalletVersion>::storage_version();
+    if version.map_or(false, |v| v >= PalletVersion { major: 3, minor: 1, patch: 0 }) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    //每个拥有者的Kitty
+    let mut owned: BTreeMap<T::AccountId, Vec<T::KittyIndex>> = BTreeMap::new();
+    let mut reads = 1u64;
+    let mut writes = 0u64;
+    for (kitty_id, owner) in Owner::<T>::iter() {
+        reads += 1;
+        if let Some(owner) = owner {
+            owned.entry(owner).or_insert_with(Vec::new).push(kitty_id);
+        }
+    }
+
+    for (who, _) in frame_system::Account::<T>::iter() {
+        reads += 1;
+        let mut available = T::Currency::reserved_balance(&who);
+        let kitties = owned.remove(&who).unwrap_or_default();
+
+        //已记录质押的Kitty先占用对应的质押
+        let (recorded, unrecorded): (Vec<_>, Vec<_>) = kitties.into_iter()
+            .partition(|kitty_id| KittyDeposits::<T>::contains_key(kitty_id));
+        reads += (recorded.len() + unrecorded.len()) as u64;
+        for kitty_id in recorded {
+            available = available.saturating_sub(KittyDeposits::<T>::get(kitty_id));
+        }
+
+        for kitty_id in unrecorded {
+            let deposit: BalanceOf<T> = T::KittyReserveMoney::get().min(available);
+            available = available.saturating_sub(deposit);
+            KittyDeposits::<T>::insert(kitty_id, deposit);
+            writes += 1;
+        }
+
+        if !available.is_zero() {
+            T::Currency::unreserve(&who, available);
+            writes += 1;
+        }
+    }
+
+    T::DbWeight::get().reads_writes(reads, writes)
+}
+
+//3.1.0之前没有按拥有者索引Kitty，也没有记录创作者：根据Owner重建OwnedKitties，
+//旧版没有记录铸造者，以迁移时的拥有者作为创作者，已迁移过的链不做任何修改
+//拥有数量超过MaxKittiesOwned的部分无法记入索引，这些Kitty仍然可以转移和销毁
+pub fn migrate_owned_kitties<T: Config>() -> Weight {
+    let version = <Pallet<T> as GetPalletVersion>::storage_version();
+    if version.map_or(false, |v| v >= PalletVersion { major: 3, minor: 1, patch: 0 }) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut reads = 1u64;
+    let mut writes = 0u64;
+    for (kitty_id, owner) in Owner::<T>::iter() {
+        reads += 1;
+        let owner = match owner {
+            Some(owner) => owner,
+            None => continue,
+        };
+
+        reads += 2;
+        OwnedKitties::<T>::mutate(&owner, |kitties| {
+            if !kitties.contains(&kitty_id) {
+                let _ = kitties.try_push(kitty_id);
+            }
+        });
+        if !KittyCreators::<T>::contains_key(kitty_id) {
+            KittyCreators::<T>::insert(kitty_id, owner);
+            writes += 1;
+        }
+        writes += 1;
+    }
+
+    T::DbWeight::get().reads_writes(reads, writes)
+}
+
+//把KittiesCount迁移为NextKittyId，并根据现存的Kitty补齐供应统计，已迁移过的链不做任何修改
+//基因模型之前的Kitty没有血统记录，DNA中代数和冷却等级的位置是随机值，补记为没有父母的初代Kitty并清零这几个字节
+pub fn migrate_to_next_kitty_id<T: Config>() -> Weight {
+    if NextKittyId::<T>::exists() {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let pallet = <Pallet<T> as PalletInfoAccess>::name();
+    let next = match take_storage_value::<T::KittyIndex>(pallet.as_bytes(), b"KittiesCount", &[]) {
+        Some(next) => next,
+        None => return T::DbWeight::get().reads(2),
+    };
+    NextKittyId::<T>::put(next);
+
+    //KittyIndex从1开始分配，分配过的都已铸造，不再存在的即为已销毁
+    let minted = next.saturating_sub(1u32.into()).saturated_into::<u64>();
+    let mut live = 0u64;
+    let mut legacy = 0u64;
+    for (kitty_id, kitty) in Kitties::<T>::iter() {
+        let kitty = match kitty {
+            Some(kitty) => kitty,
+            None => continue,
+        };
+        live += 1;
+
+        let generation = match Lineages::<T>::get(kitty_id) {
+            Some(lineage) => lineage.generation,
+            None => {
+                legacy += 1;
+                Kitties::<T>::insert(kitty_id, Some(Kitty(genome::random_genome(kitty.0))));
+                Lineages::<T>::insert(kitty_id, KittyLineage {
+                    parents: None,
+                    generation: 0,
+                    born_at: Zero::zero(),
+                });
+                0
+            },
+        };
+        GenerationSupply::<T>::mutate(generation, |supply| *supply = supply.saturating_add(1));
+    }
+
+    KittiesMinted::<T>::put(minted);
+    KittiesBurned::<T>::put(minted.saturating_sub(live));
+    TotalSupply::<T>::put(live);
+
+    T::DbWeight::get().reads_writes(live * 2 + 2, live + legacy * 2 + 5)
+}
@@ -1,11 +1,12 @@
 use crate as pallet_kitties;
 
 use sp_core::H256;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, traits::{Currency, GenesisBuild, OnUnbalanced}};
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup}, testing::Header,
+	traits::{BlakeTwo256, IdentityLookup}, testing::Header, Perbill,
 };
 use frame_system as system;
+use std::cell::RefCell;
 
 
 
@@ -22,7 +23,7 @@ frame_support::construct_runtime!(
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
-		KittiesModule: pallet_kitties::{Pallet, Call, Storage, Event<T>},
+		KittiesModule: pallet_kitties::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -31,9 +32,64 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
     pub const ExistentialDeposit: u64 = 1;
 	pub const KittyReserveMoney: u32 = 1_000_000_000;
+	pub const MaxKittiesOwned: u32 = 3;
+	pub const MaxAncestryDepth: u32 = 4;
+	pub const BaseCooldown: u64 = 2;
+	pub const MaxBirthsPerBlock: u32 = 2;
+	pub const MaxAuctionDuration: u64 = 100;
+	pub const MaxAuctionsPerBlock: u32 = 2;
+	pub const AntiSnipeDuration: u64 = 3;
+	pub const MinBidIncrement: u64 = 1_000_000_000;
+	pub const MarketplaceFee: Perbill = Perbill::from_percent(10);
+	pub const CreatorRoyalty: Perbill = Perbill::from_percent(5);
+	pub const MaxOffersPerKitty: u32 = 2;
+	pub const MaxOffersPerBidder: u32 = 2;
+	pub const MaxOfferDuration: u64 = 100;
+	pub const MaxNameLength: u32 = 8;
+	pub const MaxMetadataLength: u32 = 16;
+	pub const MetadataDepositPerByte: u64 = 1_000_000;
 
 }
 
+thread_local! {
+	static GESTATION_PERIOD: RefCell<u64> = RefCell::new(0);
+	static UNIQUE_NAMES: RefCell<bool> = RefCell::new(true);
+}
+
+//怀孕区块数默认为0，后代立即出生；测试怀孕时通过set_gestation_period修改
+pub struct GestationPeriod;
+impl frame_support::traits::Get<u64> for GestationPeriod {
+	fn get() -> u64 {
+		GESTATION_PERIOD.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_gestation_period(period: u64) {
+	GESTATION_PERIOD.with(|v| *v.borrow_mut() = period);
+}
+
+//默认名字不能重复；测试重名时通过set_unique_names修改
+pub struct UniqueNames;
+impl frame_support::traits::Get<bool> for UniqueNames {
+	fn get() -> bool {
+		UNIQUE_NAMES.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_unique_names(unique: bool) {
+	UNIQUE_NAMES.with(|v| *v.borrow_mut() = unique);
+}
+
+//市场手续费存入的国库账户
+pub const TREASURY: u64 = 100;
+
+pub struct FeeToTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for FeeToTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+		Balances::resolve_creating(&TREASURY, amount);
+	}
+}
+
 impl system::Config for Test {
 	type BaseCallFilter = ();
 	type BlockWeights = ();
@@ -77,12 +133,37 @@ impl pallet_kitties::Config for Test {
     type KittyIndex =  u32;
 	type KittyReserveMoney = KittyReserveMoney;
     type Currency = Balances;
+	type MaxKittiesOwned = MaxKittiesOwned;
+	type MaxAncestryDepth = MaxAncestryDepth;
+	type BaseCooldown = BaseCooldown;
+	type GestationPeriod = GestationPeriod;
+	type MaxBirthsPerBlock = MaxBirthsPerBlock;
+	type MaxAuctionDuration = MaxAuctionDuration;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type AntiSnipeDuration = AntiSnipeDuration;
+	type MinBidIncrement = MinBidIncrement;
+	type MarketplaceFee = MarketplaceFee;
+	type CreatorRoyalty = CreatorRoyalty;
+	type OnMarketplaceFee = FeeToTreasury;
+	type MaxOffersPerKitty = MaxOffersPerKitty;
+	type MaxOffersPerBidder = MaxOffersPerBidder;
+	type MaxOfferDuration = MaxOfferDuration;
+	type MaxNameLength = MaxNameLength;
+	type MaxMetadataLength = MaxMetadataLength;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type UniqueNames = UniqueNames;
+	type WeightInfo = ();
 }
 
 impl pallet_randomness_collective_flip::Config for Test {}
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_kitties(vec![])
+}
+
+//创世区块中预先创建Kitty：(拥有者, DNA)
+pub fn new_test_ext_with_kitties(kitties: Vec<(u64, [u8; 16])>) -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
 		balances:
@@ -96,6 +177,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			]
 		,
 	}.assimilate_storage(&mut t).unwrap();
+	pallet_kitties::GenesisConfig::<Test> { kitties }.assimilate_storage(&mut t).unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| System::set_block_number(1));
@@ -1,5 +1,7 @@
 use crate::{Error, mock::*};
-use frame_support::{assert_ok, assert_noop};
+use frame_support::{assert_ok, assert_noop, traits::{Hooks, ReservableCurrency}};
+use codec::Encode;
+use std::convert::TryFrom;
 
 use super::*;
 
@@ -20,7 +22,7 @@ fn create_kitty_works() {
 #[test]
 fn create_kitty_when_count_is_overflow() {
     new_test_ext().execute_with(|| {
-		KittiesCount::<Test>::put(u32::max_value());
+		NextKittyId::<Test>::put(u32::max_value());
 		assert_noop!(
 			KittiesModule::create(Origin::signed(1)), 
 			Error::<Test>::KittiesCountOverflow
@@ -69,6 +71,21 @@ fn transfer_kitty_when_is_not_owner() {
 	});
 }
 
+//转移Kitty，当新拥有者账户不存在、无法接收质押的失败用例
+#[test]
+fn transfer_kitty_when_new_owner_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 99, 1),
+			Error::<Test>::NewOwnerNotExist
+		);
+		assert_ok!(Balances::transfer(Origin::signed(1), 99, 10));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 99, 1));
+		assert_eq!(Balances::reserved_balance(99), 1_000_000_000);
+	});
+}
+
 //出售Kitty的成功用例
 #[test]
 fn sell_kitty_works() {
@@ -182,7 +199,7 @@ fn breed_kitty_works () {
 		assert_ok!(KittiesModule::create(Origin::signed(1)));
 		assert_ok!(KittiesModule::create(Origin::signed(1)));
 		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
-		assert_eq!(KittiesCount::<Test>::get(), Some(4));
+		assert_eq!(NextKittyId::<Test>::get(), 4);
 		assert_eq!(Owner::<Test>::get(3), Some(1));
 	});
 }
@@ -217,11 +234,1830 @@ fn breed_kitty_when_kitty_count_is_overflow() {
 	new_test_ext().execute_with(||{
 		assert_ok!(KittiesModule::create(Origin::signed(1)));
 		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		KittiesCount::<Test>::put(u32::max_value());
+		NextKittyId::<Test>::put(u32::max_value());
 		assert_noop!(
 			KittiesModule::breed(Origin::signed(1), 1, 2),
 			Error::<Test>::KittiesCountOverflow
 		);
 	
 	});
-}
\ No newline at end of file
+}
+//创建、转移和购买Kitty时，账户拥有的Kitty列表同步更新的用例
+#[test]
+fn owned_kitties_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(KittiesModule::owned_kitties(1).into_inner(), vec![1, 2]);
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+		assert_eq!(KittiesModule::owned_kitties(1).into_inner(), vec![2]);
+		assert_eq!(KittiesModule::owned_kitties(2).into_inner(), vec![1]);
+
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 2, 10_000_000_000));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(2), 2, 10_000_000_000));
+		assert!(KittiesModule::owned_kitties(1).is_empty());
+		assert_eq!(KittiesModule::owned_kitties(2).into_inner(), vec![1, 2]);
+	});
+}
+
+//创建Kitty，当拥有数量达到上限的失败用例
+#[test]
+fn create_kitty_when_exceed_max_owned() {
+	new_test_ext().execute_with(||{
+		for _ in 0..3 {
+			assert_ok!(KittiesModule::create(Origin::signed(1)));
+		}
+		assert_noop!(
+			KittiesModule::create(Origin::signed(1)),
+			Error::<Test>::ExceedMaxKittiesOwned
+		);
+	});
+}
+
+//转移Kitty，当接收者拥有数量达到上限的失败用例
+#[test]
+fn transfer_kitty_when_exceed_max_owned() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		for _ in 0..3 {
+			assert_ok!(KittiesModule::create(Origin::signed(2)));
+		}
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 2, 1),
+			Error::<Test>::ExceedMaxKittiesOwned
+		);
+	});
+}
+
+//购买Kitty，当买方拥有数量达到上限的失败用例
+#[test]
+fn buy_kitty_when_exceed_max_owned() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000));
+		for _ in 0..3 {
+			assert_ok!(KittiesModule::create(Origin::signed(2)));
+		}
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(2), 1, 10_000_000_000),
+			Error::<Test>::ExceedMaxKittiesOwned
+		);
+	});
+}
+
+//繁殖Kitty，当拥有数量达到上限的失败用例
+#[test]
+fn breed_kitty_when_exceed_max_owned() {
+	new_test_ext().execute_with(||{
+		for _ in 0..3 {
+			assert_ok!(KittiesModule::create(Origin::signed(1)));
+		}
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::ExceedMaxKittiesOwned
+		);
+	});
+}
+
+//解码Kitty性状的用例：初代Kitty代数和冷却等级为0，后代代数加一
+#[test]
+fn decode_traits_works() {
+	new_test_ext().execute_with(||{
+		assert_eq!(KittiesModule::decode_traits(1), None);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		let traits = KittiesModule::decode_traits(1).unwrap();
+		assert_eq!(traits.generation, 0);
+		assert_eq!(traits.cooldown_index, 0);
+
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_eq!(KittiesModule::decode_traits(3).unwrap().generation, 1);
+	});
+}
+
+//繁殖基因：不遗传隐性基因、不突变时，后代的两个等位基因分别来自父母的显性等位基因
+#[test]
+fn breed_genome_inherits_dominant_alleles() {
+	let mut dna_1 = [10u8; 16];
+	let mut dna_2 = [20u8; 16];
+	dna_1[genome::GENERATION..genome::ENTROPY].copy_from_slice(&[3, 0, 1]);
+	dna_2[genome::GENERATION..genome::ENTROPY].copy_from_slice(&[5, 0, 2]);
+	dna_1[genome::COLOR + 1] = 11;
+	dna_2[genome::COLOR + 1] = 21;
+
+	//显隐顺序位为1：母方的等位基因为显性
+	let child = genome::breed_genome(&dna_1, &dna_2, &[0xFF; 16]);
+	assert_eq!(child[genome::COLOR], 20);
+	assert_eq!(child[genome::COLOR + 1], 10);
+	assert_eq!(genome::generation(&child), 6);
+	assert_eq!(genome::cooldown_index(&child), 3);
+
+	let traits = genome::decode_traits(&child);
+	assert_eq!(traits.color, genome::Color::Orange);
+	assert_eq!(traits.pattern, genome::Pattern::Solid);
+	assert_eq!(traits.eyes, genome::Eyes::Blue);
+}
+
+//繁殖基因：隐性等位基因遗传和突变的用例
+#[test]
+fn breed_genome_recessive_and_mutation() {
+	let mut dna_1 = [0u8; 16];
+	let dna_2 = [0u8; 16];
+	dna_1[genome::COLOR] = 1;
+	dna_1[genome::COLOR + 1] = 2;
+
+	//父方遗传隐性等位基因，显隐顺序位为0，不突变
+	let mut selector = [0xFF; 16];
+	selector[0] = 0;
+	selector[2] = 0xFE;
+	let child = genome::breed_genome(&dna_1, &dna_2, &selector);
+	assert_eq!(child[genome::COLOR], 2);
+
+	//显性等位基因突变
+	selector[3] = 4;
+	let child = genome::breed_genome(&dna_1, &dna_2, &selector);
+	assert_eq!(child[genome::COLOR], 7);
+}
+
+//繁殖Kitty时记录父母、代数和出生区块的用例
+#[test]
+fn breed_kitty_records_lineage() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		System::set_block_number(5);
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_eq!(
+			KittiesModule::lineage(1),
+			Some(KittyLineage { parents: None, generation: 0, born_at: 1 })
+		);
+		assert_eq!(
+			KittiesModule::lineage(3),
+			Some(KittyLineage { parents: Some((1, 2)), generation: 1, born_at: 5 })
+		);
+	});
+}
+
+//繁殖Kitty，当调用者不是母方拥有者的失败用例
+#[test]
+fn breed_kitty_when_is_not_owner() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(2), 1, 2),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+//使用他人授权的父方繁殖Kitty，授权只能使用一次的用例
+#[test]
+fn breed_kitty_with_siring_approval_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::SiringNotApproved
+		);
+
+		assert_noop!(
+			KittiesModule::approve_siring(Origin::signed(1), 2, Some(1)),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::approve_siring(Origin::signed(2), 2, Some(1)));
+		assert_eq!(KittiesModule::siring_approval(2), Some(1));
+
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_eq!(Owner::<Test>::get(3), Some(1));
+		assert_eq!(KittiesModule::siring_approval(2), None);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::SiringNotApproved
+		);
+	});
+}
+
+//转移Kitty时清除繁殖授权的用例
+#[test]
+fn transfer_kitty_clears_siring_approval() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::approve_siring(Origin::signed(2), 1, Some(1)));
+		assert_ok!(KittiesModule::transfer(Origin::signed(2), 3, 1));
+		assert_eq!(KittiesModule::siring_approval(1), None);
+	});
+}
+
+//繁殖Kitty，当父母之间是亲子关系的失败用例
+#[test]
+fn breed_kitty_when_parent_and_child() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 3),
+			Error::<Test>::ParentChildBreeding
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 3, 2),
+			Error::<Test>::ParentChildBreeding
+		);
+	});
+}
+
+//繁殖Kitty，当父母是兄弟姐妹的失败用例
+#[test]
+fn breed_kitty_when_siblings() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 3));
+		System::set_block_number(3);
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_ok!(KittiesModule::approve_siring(Origin::signed(2), 3, Some(1)));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 4, 3),
+			Error::<Test>::SiblingBreeding
+		);
+	});
+}
+
+//按深度追溯Kitty血统的用例
+#[test]
+fn ancestry_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 3));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::breed(Origin::signed(2), 3, 4));
+
+		assert_eq!(KittiesModule::ancestry(5, 0), vec![(5, Some((3, 4)))]);
+		assert_eq!(
+			KittiesModule::ancestry(5, 1),
+			vec![(5, Some((3, 4))), (3, Some((1, 2))), (4, None)]
+		);
+		assert_eq!(
+			KittiesModule::ancestry(5, 10),
+			vec![(5, Some((3, 4))), (3, Some((1, 2))), (4, None), (1, None), (2, None)]
+		);
+		assert!(KittiesModule::ancestry(6, 10).is_empty());
+	});
+}
+
+//繁殖后父母进入冷却，冷却结束前不能再次繁殖的用例
+#[test]
+fn breed_kitty_when_in_cooldown() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_eq!(KittiesModule::ready_at(1), Some(3));
+		assert_eq!(KittiesModule::ready_at(2), Some(3));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 3));
+
+		System::set_block_number(2);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::KittyInCooldown
+		);
+
+		System::set_block_number(3);
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+	});
+}
+
+//冷却时间随冷却等级翻倍的用例
+#[test]
+fn breed_kitty_cooldown_grows_with_cooldown_index() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		let mut dna = KittiesModule::kitties(1).unwrap().0;
+		dna[genome::COOLDOWN] = 3;
+		Kitties::<Test>::insert(1, Some(Kitty(dna)));
+
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_eq!(KittiesModule::ready_at(1), Some(1 + 2 * 8));
+		assert_eq!(KittiesModule::ready_at(2), Some(1 + 2));
+	});
+}
+
+//怀孕期满后在on_initialize中出生的用例
+#[test]
+fn breed_kitty_with_gestation_works() {
+	new_test_ext().execute_with(||{
+		set_gestation_period(5);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+
+		assert_eq!(KittiesModule::pregnancy(1).map(|p| (p.sire, p.breeder, p.due_at)), Some((2, 1, 6)));
+		assert_eq!(KittiesModule::birth_schedule(6).into_inner(), vec![1]);
+		assert_eq!(KittiesModule::pending_births(1), 1);
+		assert_eq!(Owner::<Test>::get(3), None);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittyPregnant(1, 1, 2, 6))
+		);
+
+		System::set_block_number(6);
+		KittiesModule::on_initialize(6);
+
+		assert_eq!(Owner::<Test>::get(3), Some(1));
+		assert_eq!(KittiesModule::pregnancy(1), None);
+		assert!(KittiesModule::birth_schedule(6).is_empty());
+		assert_eq!(KittiesModule::pending_births(1), 0);
+		assert_eq!(KittiesModule::lineage(3).map(|l| (l.parents, l.born_at)), Some((Some((1, 2)), 6)));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittyBorn(1, 3, 1, 2))
+		);
+	});
+}
+
+//繁殖Kitty，当父母正在怀孕的失败用例
+#[test]
+fn breed_kitty_when_pregnant() {
+	new_test_ext().execute_with(||{
+		set_gestation_period(5);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+
+		System::set_block_number(3);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 2, 1),
+			Error::<Test>::KittyIsPregnant
+		);
+	});
+}
+
+//尚未出生的后代计入拥有上限的用例
+#[test]
+fn pending_births_count_towards_max_owned() {
+	new_test_ext().execute_with(||{
+		set_gestation_period(5);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_noop!(
+			KittiesModule::create(Origin::signed(1)),
+			Error::<Test>::ExceedMaxKittiesOwned
+		);
+	});
+}
+
+//繁殖Kitty，当同一区块出生数量达到上限的失败用例
+#[test]
+fn breed_kitty_when_too_many_births() {
+	new_test_ext().execute_with(||{
+		set_gestation_period(5);
+		for who in 1..=3 {
+			assert_ok!(KittiesModule::create(Origin::signed(who)));
+			assert_ok!(KittiesModule::create(Origin::signed(who)));
+		}
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_ok!(KittiesModule::breed(Origin::signed(2), 3, 4));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(3), 5, 6),
+			Error::<Test>::TooManyBirths
+		);
+	});
+}
+
+//出租和取消出租繁殖的用例
+#[test]
+fn offer_siring_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_noop!(
+			KittiesModule::offer_siring(Origin::signed(1), 1, 5_000_000_000),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::cancel_siring_offer(Origin::signed(2), 1),
+			Error::<Test>::SireNotOffered
+		);
+
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(2), 1, 5_000_000_000));
+		assert_eq!(KittiesModule::siring_offer(1), Some(5_000_000_000));
+
+		assert_noop!(
+			KittiesModule::cancel_siring_offer(Origin::signed(1), 1),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::cancel_siring_offer(Origin::signed(2), 1));
+		assert_eq!(KittiesModule::siring_offer(1), None);
+	});
+}
+
+//转移Kitty时取消出租繁殖的用例
+#[test]
+fn transfer_kitty_clears_siring_offer() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(2), 1, 5_000_000_000));
+		assert_ok!(KittiesModule::transfer(Origin::signed(2), 3, 1));
+		assert_eq!(KittiesModule::siring_offer(1), None);
+	});
+}
+
+//支付配种费繁殖的成功用例
+#[test]
+fn breed_with_sire_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(2), 2, 5_000_000_000));
+		let balance_1 = Balances::free_balance(1);
+		let balance_2 = Balances::free_balance(2);
+
+		assert_ok!(KittiesModule::breed_with_sire(Origin::signed(1), 1, 2, 5_000_000_000));
+
+		assert_eq!(Owner::<Test>::get(3), Some(1));
+		assert_eq!(Owner::<Test>::get(2), Some(2));
+		//支付配种费并为后代质押
+		assert_eq!(Balances::free_balance(1), balance_1 - 5_000_000_000 - 1_000_000_000);
+		assert_eq!(Balances::free_balance(2), balance_2 + 5_000_000_000);
+		assert_eq!(KittiesModule::ready_at(2), Some(3));
+		assert_eq!(KittiesModule::siring_offer(2), Some(5_000_000_000));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::SiredWithFee(1, 1, 2, 5_000_000_000))
+		);
+	});
+}
+
+//支付配种费繁殖的失败用例：未出租、付款不足、使用自己的父方、父方冷却中
+#[test]
+fn breed_with_sire_failures() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::breed_with_sire(Origin::signed(1), 1, 2, 5_000_000_000),
+			Error::<Test>::SireNotOffered
+		);
+
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(2), 2, 5_000_000_000));
+		assert_noop!(
+			KittiesModule::breed_with_sire(Origin::signed(1), 1, 2, 1_000_000_000),
+			Error::<Test>::MoneyIsNotEnough
+		);
+		assert_noop!(
+			KittiesModule::breed_with_sire(Origin::signed(3), 1, 2, 5_000_000_000),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(1), 3, 5_000_000_000));
+		assert_noop!(
+			KittiesModule::breed_with_sire(Origin::signed(1), 1, 3, 5_000_000_000),
+			Error::<Test>::InvalidKittyBuyer
+		);
+
+		assert_ok!(KittiesModule::breed_with_sire(Origin::signed(1), 1, 2, 5_000_000_000));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 4, 4));
+		assert_noop!(
+			KittiesModule::breed_with_sire(Origin::signed(1), 3, 2, 5_000_000_000),
+			Error::<Test>::KittyInCooldown
+		);
+	});
+}
+
+fn english(starting_price: u64) -> AuctionKind<u64> {
+	AuctionKind::English { starting_price }
+}
+
+fn last_event() -> crate::mock::Event {
+	System::events().last().unwrap().event.clone()
+}
+
+//发起拍卖的成功和失败用例
+#[test]
+fn create_auction_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(2), 1, english(10_000_000_000), 10),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 0),
+			Error::<Test>::InvalidAuctionDuration
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 101),
+			Error::<Test>::InvalidAuctionDuration
+		);
+		assert_noop!(
+			KittiesModule::create_auction(
+				Origin::signed(1), 1,
+				AuctionKind::Dutch { start_price: 1_000_000_000, end_price: 2_000_000_000 }, 10
+			),
+			Error::<Test>::InvalidAuctionPrice
+		);
+
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		assert_eq!(
+			KittiesModule::auctions(1),
+			Some(Auction { seller: 1, kind: english(10_000_000_000), start: 1, end: 11, best_bid: None })
+		);
+		assert_eq!(KittiesModule::auction_endings(11).into_inner(), vec![1]);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+//发起拍卖，当Kitty处于出售状态的失败用例
+#[test]
+fn create_auction_when_kitty_is_on_sale() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000));
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10),
+			Error::<Test>::KittyIsOnSale
+		);
+	});
+}
+
+//拍卖中的Kitty不能转移和出售的用例
+#[test]
+fn kitty_in_auction_cannot_be_transferred_or_sold() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 2, 1),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+//英式拍卖：出价质押、退还被超过的出价者，到期在on_finalize中成交
+#[test]
+fn english_auction_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(2), 1, 9_000_000_000),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 1, 10_000_000_000));
+		assert_eq!(Balances::reserved_balance(2), 10_000_000_000);
+
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(3), 1, 10_500_000_000),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(KittiesModule::bid(Origin::signed(3), 1, 11_000_000_000));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 11_000_000_000);
+		assert_eq!(last_event(), crate::mock::Event::KittiesModule(crate::Event::<Test>::BidPlaced(3, 1, 11_000_000_000)));
+
+		let seller_balance = Balances::free_balance(1);
+		System::set_block_number(11);
+		KittiesModule::on_finalize(11);
+
+		assert_eq!(Owner::<Test>::get(1), Some(3));
+		assert_eq!(KittiesModule::owned_kitties(3).into_inner(), vec![1]);
+		//出价被支付，Kitty的质押随Kitty转给买家
+		assert_eq!(Balances::reserved_balance(3), 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		//成交价扣除10%的市场手续费
+		assert_eq!(Balances::free_balance(1), seller_balance + 11_000_000_000 - 1_100_000_000);
+		assert_eq!(Balances::free_balance(TREASURY), 1_100_000_000);
+		assert_eq!(KittiesModule::auctions(1), None);
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::AuctionSettled(1, 3, 1, 11_000_000_000, 1_100_000_000, 0))
+		);
+	});
+}
+
+//英式拍卖：结束前出价延长拍卖的防狙击用例
+#[test]
+fn english_auction_anti_sniping_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+
+		System::set_block_number(10);
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 1, 10_000_000_000));
+		assert_eq!(KittiesModule::auctions(1).map(|a| a.end), Some(13));
+		assert!(KittiesModule::auction_endings(11).is_empty());
+		assert_eq!(KittiesModule::auction_endings(13).into_inner(), vec![1]);
+		assert!(System::events().iter().any(|record|
+			record.event == crate::mock::Event::KittiesModule(crate::Event::<Test>::AuctionExtended(1, 13))
+		));
+
+		System::set_block_number(11);
+		KittiesModule::on_finalize(11);
+		assert_eq!(Owner::<Test>::get(1), Some(1));
+
+		System::set_block_number(13);
+		KittiesModule::on_finalize(13);
+		assert_eq!(Owner::<Test>::get(1), Some(2));
+	});
+}
+
+//出价的失败用例：拍卖不存在、卖家出价、余额不足、拍卖已结束
+#[test]
+fn bid_failures() {
+	new_test_ext().execute_with(||{
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(2), 1, 10_000_000_000),
+			Error::<Test>::AuctionNotExist
+		);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(1), 1, 10_000_000_000),
+			Error::<Test>::InvalidKittyBuyer
+		);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(5), 1, 10_000_000_000),
+			Error::<Test>::MoneyIsNotEnough
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(2), 1, 10_000_000_000),
+			Error::<Test>::AuctionExpired
+		);
+	});
+}
+
+//英式拍卖没有出价时到期关闭的用例
+#[test]
+fn english_auction_without_bids_closes() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		System::set_block_number(11);
+		KittiesModule::on_finalize(11);
+
+		assert_eq!(Owner::<Test>::get(1), Some(1));
+		assert_eq!(KittiesModule::auctions(1), None);
+		assert_eq!(last_event(), crate::mock::Event::KittiesModule(crate::Event::<Test>::AuctionClosed(1, 1)));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+	});
+}
+
+//英式拍卖到期时最高出价者已达到拥有上限，退还质押并关闭拍卖的用例
+#[test]
+fn english_auction_refunds_when_winner_cannot_own() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 1, 10_000_000_000));
+		for _ in 0..3 {
+			assert_ok!(KittiesModule::create(Origin::signed(2)));
+		}
+
+		System::set_block_number(11);
+		KittiesModule::on_finalize(11);
+
+		assert_eq!(Owner::<Test>::get(1), Some(1));
+		assert_eq!(Balances::reserved_balance(2), 3 * 1_000_000_000);
+		assert_eq!(last_event(), crate::mock::Event::KittiesModule(crate::Event::<Test>::AuctionClosed(1, 1)));
+	});
+}
+
+//荷式拍卖：价格线性降低，按当前价格立即成交的用例
+#[test]
+fn dutch_auction_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(
+			Origin::signed(1), 1,
+			AuctionKind::Dutch { start_price: 20_000_000_000, end_price: 10_000_000_000 }, 10
+		));
+
+		System::set_block_number(6);
+		let auction = KittiesModule::auctions(1).unwrap();
+		assert_eq!(KittiesModule::dutch_price(&auction, 6), 15_000_000_000);
+		assert_eq!(KittiesModule::dutch_price(&auction, 11), 10_000_000_000);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(2), 1, 14_000_000_000),
+			Error::<Test>::BidTooLow
+		);
+
+		let buyer_balance = Balances::free_balance(2);
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 1, 16_000_000_000));
+		assert_eq!(Owner::<Test>::get(1), Some(2));
+		assert_eq!(Balances::free_balance(2), buyer_balance - 15_000_000_000);
+		assert_eq!(KittiesModule::auctions(1), None);
+		assert!(KittiesModule::auction_endings(11).is_empty());
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::AuctionSettled(1, 2, 1, 15_000_000_000, 1_500_000_000, 0))
+		);
+	});
+}
+
+//取消拍卖的成功和失败用例
+#[test]
+fn cancel_auction_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 2, english(10_000_000_000), 10));
+		assert_noop!(
+			KittiesModule::cancel_auction(Origin::signed(2), 1),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 2, 10_000_000_000));
+		assert_noop!(
+			KittiesModule::cancel_auction(Origin::signed(1), 2),
+			Error::<Test>::AuctionHasBids
+		);
+
+		assert_ok!(KittiesModule::cancel_auction(Origin::signed(1), 1));
+		assert_eq!(KittiesModule::auctions(1), None);
+		assert_eq!(KittiesModule::auction_endings(11).into_inner(), vec![2]);
+		assert_noop!(
+			KittiesModule::cancel_auction(Origin::signed(1), 1),
+			Error::<Test>::AuctionNotExist
+		);
+	});
+}
+
+//取消出售Kitty的成功和失败用例
+#[test]
+fn cancel_sale_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::cancel_sale(Origin::signed(1), 1),
+			Error::<Test>::KittyIsNotOnSale
+		);
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000));
+		assert_noop!(
+			KittiesModule::cancel_sale(Origin::signed(2), 1),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::cancel_sale(Origin::signed(1), 2),
+			Error::<Test>::InvalidKittyIndex
+		);
+
+		assert_ok!(KittiesModule::cancel_sale(Origin::signed(1), 1));
+		assert_eq!(KittiesModule::kitties_price(1), None);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(2), 1, 10_000_000_000),
+			Error::<Test>::KittyIsNotOnSale
+		);
+	});
+}
+
+//购买Kitty时支付市场手续费和创作者版税的用例
+#[test]
+fn buy_kitty_pays_fee_and_royalty() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(KittiesModule::kitty_creator(1), Some(1));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(2), 1, 10_000_000_000));
+
+		let creator_balance = Balances::free_balance(1);
+		let seller_balance = Balances::free_balance(2);
+		let buyer_balance = Balances::free_balance(3);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(3), 1, 10_000_000_000));
+
+		assert_eq!(Balances::free_balance(3), buyer_balance - 10_000_000_000);
+		assert_eq!(Balances::free_balance(TREASURY), 1_000_000_000);
+		assert_eq!(Balances::free_balance(1), creator_balance + 500_000_000);
+		assert_eq!(Balances::free_balance(2), seller_balance + 8_500_000_000);
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittyBuy(3, 1, 10_000_000_000, 1_000_000_000, 500_000_000))
+		);
+	});
+}
+
+//创作者出售自己的Kitty时不收版税的用例
+#[test]
+fn buy_kitty_from_creator_pays_no_royalty() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(2), 1, 10_000_000_000));
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittyBuy(2, 1, 10_000_000_000, 1_000_000_000, 0))
+		);
+	});
+}
+
+//报价的成功用例：质押报价金额，重复报价替换之前的报价
+#[test]
+fn make_offer_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 10));
+		assert_eq!(Balances::reserved_balance(2), 5_000_000_000);
+		assert_eq!(
+			KittiesModule::offers(1).into_inner(),
+			vec![Offer { bidder: 2, amount: 5_000_000_000, expires_at: 11 }]
+		);
+		assert_eq!(KittiesModule::offers_of(2).into_inner(), vec![1]);
+
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 6_000_000_000, 20));
+		assert_eq!(Balances::reserved_balance(2), 6_000_000_000);
+		assert_eq!(
+			KittiesModule::offers(1).into_inner(),
+			vec![Offer { bidder: 2, amount: 6_000_000_000, expires_at: 21 }]
+		);
+		assert_eq!(KittiesModule::offers_of(2).into_inner(), vec![1]);
+	});
+}
+
+//报价的失败用例
+#[test]
+fn make_offer_failures() {
+	new_test_ext().execute_with(||{
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 10),
+			Error::<Test>::InvalidKittyIndex
+		);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(1), 1, 5_000_000_000, 10),
+			Error::<Test>::InvalidKittyBuyer
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(2), 1, 0, 10),
+			Error::<Test>::OfferTooLow
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 0),
+			Error::<Test>::InvalidOfferDuration
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 101),
+			Error::<Test>::InvalidOfferDuration
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(5), 1, 5_000_000_000, 10),
+			Error::<Test>::MoneyIsNotEnough
+		);
+	});
+}
+
+//报价数量达到每个Kitty和每个账户上限的失败用例
+#[test]
+fn make_offer_when_too_many_offers() {
+	new_test_ext().execute_with(||{
+		for _ in 0..3 {
+			assert_ok!(KittiesModule::create(Origin::signed(1)));
+		}
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 10));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(3), 1, 5_000_000_000, 10));
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(4), 1, 5_000_000_000, 10),
+			Error::<Test>::TooManyOffers
+		);
+
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 2, 5_000_000_000, 10));
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(2), 3, 5_000_000_000, 10),
+			Error::<Test>::TooManyOffers
+		);
+	});
+}
+
+//报价已满时移除过期的报价或低于新报价的最低报价并退还质押的用例
+#[test]
+fn make_offer_evicts_when_book_is_full() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 1_000_000_000, 5));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(3), 1, 2_000_000_000, 10));
+
+		//没有过期的报价时，不高于最低报价的新报价被拒绝
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(4), 1, 1_000_000_000, 10),
+			Error::<Test>::TooManyOffers
+		);
+
+		//高于最低报价时移除最低的报价
+		assert_ok!(KittiesModule::make_offer(Origin::signed(4), 1, 1_500_000_000, 10));
+		assert_eq!(
+			KittiesModule::offers(1).iter().map(|offer| offer.bidder).collect::<Vec<_>>(),
+			vec![3, 4]
+		);
+		assert!(KittiesModule::offers_of(2).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::OfferEvicted(2, 1, 1_000_000_000))));
+
+		//过期的报价优先被移除，即使新报价更低
+		System::set_block_number(11);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 1, 10));
+		assert_eq!(
+			KittiesModule::offers(1).iter().map(|offer| offer.bidder).collect::<Vec<_>>(),
+			vec![4, 2]
+		);
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+//撤回报价的用例
+#[test]
+fn withdraw_offer_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::withdraw_offer(Origin::signed(2), 1),
+			Error::<Test>::OfferNotExist
+		);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 10));
+		assert_ok!(KittiesModule::withdraw_offer(Origin::signed(2), 1));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(KittiesModule::offers(1).is_empty());
+		assert!(KittiesModule::offers_of(2).is_empty());
+	});
+}
+
+//接受报价的成功用例：质押的报价金额扣除手续费后付给拥有者，Kitty转给报价者
+#[test]
+fn accept_offer_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 10_000_000_000, 10));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(3), 1, 8_000_000_000, 10));
+		let owner_balance = Balances::free_balance(1);
+
+		assert_ok!(KittiesModule::accept_offer(Origin::signed(1), 1, 2));
+
+		assert_eq!(Owner::<Test>::get(1), Some(2));
+		assert_eq!(Balances::reserved_balance(2), 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(TREASURY), 1_000_000_000);
+		assert_eq!(Balances::free_balance(1), owner_balance + 9_000_000_000);
+		assert_eq!(
+			KittiesModule::offers(1).into_inner(),
+			vec![Offer { bidder: 3, amount: 8_000_000_000, expires_at: 11 }]
+		);
+		assert!(KittiesModule::offers_of(2).is_empty());
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::OfferAccepted(1, 2, 1, 10_000_000_000, 1_000_000_000, 0))
+		);
+	});
+}
+
+//接受报价的失败用例：不是拥有者、报价不存在、报价过期、Kitty正在拍卖
+#[test]
+fn accept_offer_failures() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 10_000_000_000, 10));
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(3), 1, 2),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(1), 1, 3),
+			Error::<Test>::OfferNotExist
+		);
+
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 5));
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(1), 1, 2),
+			Error::<Test>::KittyInAuction
+		);
+		assert_ok!(KittiesModule::cancel_auction(Origin::signed(1), 1));
+
+		System::set_block_number(11);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(1), 1, 2),
+			Error::<Test>::OfferExpired
+		);
+		assert_ok!(KittiesModule::withdraw_offer(Origin::signed(2), 1));
+	});
+}
+
+//Kitty的质押随所有权转移的用例：创建、转移、购买和销毁
+#[test]
+fn deposit_follows_kitty() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000);
+		assert_eq!(KittiesModule::kitty_deposit(1), 1_000_000_000);
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 1_000_000_000);
+
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(2), 1, 10_000_000_000));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(3), 1, 10_000_000_000));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 1_000_000_000);
+
+		let balance_3 = Balances::free_balance(3);
+		assert_ok!(KittiesModule::burn(Origin::signed(3), 1));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), balance_3 + 1_000_000_000);
+		assert_eq!(KittiesModule::kitty_deposit(1), 0);
+	});
+}
+
+//繁殖时繁殖者为后代质押，怀孕期间质押保持，出生后随后代转移的用例
+#[test]
+fn breed_reserves_deposit_for_offspring() {
+	new_test_ext().execute_with(||{
+		set_gestation_period(5);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 2 * 1_000_000_000);
+
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_eq!(Balances::reserved_balance(1), 3 * 1_000_000_000);
+		assert_eq!(KittiesModule::pregnancy(1).map(|p| p.deposit), Some(1_000_000_000));
+
+		KittiesModule::on_initialize(6);
+		assert_eq!(Owner::<Test>::get(3), Some(1));
+		assert_eq!(KittiesModule::kitty_deposit(3), 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(1), 3 * 1_000_000_000);
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 3));
+		assert_eq!(Balances::reserved_balance(1), 2 * 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(2), 1_000_000_000);
+		set_gestation_period(0);
+	});
+}
+
+//繁殖时繁殖者余额不足以质押的失败用例
+#[test]
+fn breed_when_money_is_not_enough_for_deposit() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		let free = Balances::free_balance(1);
+		assert_ok!(Balances::reserve(&1, free - 1));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::MoneyIsNotEnough
+		);
+	});
+}
+
+//接受报价和拍卖成交时质押随Kitty转给买家的用例
+#[test]
+fn deposit_follows_kitty_on_offer_and_auction() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 10_000_000_000, 10));
+		assert_ok!(KittiesModule::accept_offer(Origin::signed(1), 1, 2));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 1_000_000_000);
+
+		assert_ok!(KittiesModule::create_auction(Origin::signed(2), 1, english(10_000_000_000), 5));
+		assert_ok!(KittiesModule::bid(Origin::signed(3), 1, 10_000_000_000));
+		assert_eq!(Balances::reserved_balance(3), 10_000_000_000);
+		KittiesModule::on_finalize(6);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 1_000_000_000);
+	});
+}
+
+//出价或报价会让可用余额低于存在性押金的失败用例
+#[test]
+fn bid_and_offer_when_free_balance_would_be_reaped() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		let free = Balances::free_balance(12);
+
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(12), 1, free),
+			Error::<Test>::MoneyIsNotEnough
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(12), 2, free, 10),
+			Error::<Test>::MoneyIsNotEnough
+		);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(12), 2, free - 1, 10));
+		assert_eq!(Balances::free_balance(12), 1);
+	});
+}
+
+//拍卖到期时最高出价者支付后账户被回收，回滚结算并退还质押的用例
+#[test]
+fn english_auction_refunds_when_winner_is_reaped() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		let free = Balances::free_balance(12);
+		assert_ok!(KittiesModule::bid(Origin::signed(12), 1, free - 1));
+		//出价后转走剩余的可用余额，支付出价后账户会被回收
+		assert_ok!(Balances::transfer(Origin::signed(12), 2, 1));
+		let seller_balance = Balances::free_balance(1);
+
+		System::set_block_number(11);
+		KittiesModule::on_finalize(11);
+
+		assert_eq!(Owner::<Test>::get(1), Some(1));
+		assert_eq!(KittiesModule::owned_kitties(1).into_inner(), vec![1]);
+		assert!(KittiesModule::owned_kitties(12).is_empty());
+		assert_eq!(KittiesModule::kitty_deposit(1), 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000);
+		assert_eq!(Balances::free_balance(1), seller_balance);
+		assert_eq!(Balances::free_balance(12), free - 1);
+		assert_eq!(Balances::reserved_balance(12), 0);
+		assert_eq!(last_event(), crate::mock::Event::KittiesModule(crate::Event::<Test>::AuctionClosed(1, 1)));
+	});
+}
+
+//接受报价时报价者支付后账户被回收的失败用例
+#[test]
+fn accept_offer_when_bidder_is_reaped() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		let free = Balances::free_balance(12);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(12), 1, free - 1, 10));
+		assert_ok!(Balances::transfer(Origin::signed(12), 2, 1));
+
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(1), 1, 12),
+			pallet_balances::Error::<Test>::DeadAccount
+		);
+		assert_eq!(Owner::<Test>::get(1), Some(1));
+	});
+}
+
+//销毁Kitty的成功用例：删除Kitty、出售信息和报价，退还质押，现存数量减少
+#[test]
+fn burn_kitty_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(KittiesModule::total_supply(), 2);
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000));
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(1), 2, 5_000_000_000));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 10));
+
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 1));
+
+		assert!(KittiesModule::kitties(1).is_none());
+		assert_eq!(Owner::<Test>::get(1), None);
+		assert_eq!(KittiesModule::kitties_price(1), None);
+		assert_eq!(KittiesModule::owned_kitties(1).into_inner(), vec![2]);
+		assert!(KittiesModule::offers(1).is_empty());
+		assert!(KittiesModule::offers_of(2).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000);
+		assert_eq!(KittiesModule::total_supply(), 1);
+		//KittyIndex不会被重复使用
+		assert_eq!(KittiesModule::next_id(), 3);
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittyBurned(1, 1, 1_000_000_000))
+		);
+
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 2));
+		assert_eq!(KittiesModule::siring_offer(2), None);
+		assert_eq!(KittiesModule::total_supply(), 0);
+
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(Owner::<Test>::get(3), Some(1));
+		assert_eq!(KittiesModule::total_supply(), 1);
+	});
+}
+
+//销毁Kitty按实际退还的报价数量计算权重
+#[test]
+fn burn_kitty_refunds_unused_weight() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 5_000_000_000, 10));
+
+		let info = KittiesModule::burn(Origin::signed(1), 1).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as crate::weights::WeightInfo>::burn(1)));
+		assert!(<() as crate::weights::WeightInfo>::burn(1) < <() as crate::weights::WeightInfo>::burn(MaxOffersPerKitty::get()));
+	});
+}
+
+//销毁Kitty的失败用例
+#[test]
+fn burn_kitty_failures() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::burn(Origin::signed(2), 1),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 5));
+		assert_noop!(
+			KittiesModule::burn(Origin::signed(1), 1),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::burn(Origin::signed(1), 9),
+			Error::<Test>::NotOwner
+		);
+
+		set_gestation_period(5);
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::breed(Origin::signed(2), 2, 3));
+		assert_noop!(
+			KittiesModule::burn(Origin::signed(2), 2),
+			Error::<Test>::KittyIsPregnant
+		);
+		set_gestation_period(0);
+	});
+}
+
+//创世配置预先创建Kitty的用例
+#[test]
+fn genesis_config_works() {
+	new_test_ext_with_kitties(vec![(1, [1u8; 16]), (2, [2u8; 16])]).execute_with(||{
+		assert_eq!(Owner::<Test>::get(1), Some(1));
+		assert_eq!(Owner::<Test>::get(2), Some(2));
+		assert_eq!(KittiesModule::kitties(2).map(|kitty| kitty.0), Some([2u8; 16]));
+		assert_eq!(KittiesModule::owned_kitties(1).into_inner(), vec![1]);
+		assert_eq!(KittiesModule::kitty_creator(1), Some(1));
+		assert_eq!(KittiesModule::kitty_deposit(1), 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000);
+		assert_eq!(KittiesModule::total_supply(), 2);
+
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(Owner::<Test>::get(3), Some(1));
+	});
+}
+
+//通过nonfungibles接口查询Kitty的用例
+#[test]
+fn nonfungibles_inspect_works() {
+	use frame_support::traits::tokens::nonfungibles::Inspect;
+
+	new_test_ext_with_kitties(vec![(1, [7u8; 16])]).execute_with(||{
+		assert_eq!(<KittiesModule as Inspect<u64>>::owner(&(), &1), Some(1));
+		assert_eq!(<KittiesModule as Inspect<u64>>::owner(&(), &2), None);
+		assert_eq!(<KittiesModule as Inspect<u64>>::attribute(&(), &1, b"dna"), Some(vec![7u8; 16]));
+		assert_eq!(<KittiesModule as Inspect<u64>>::attribute(&(), &1, b"generation"), Some(0x0707u16.encode()));
+		assert_eq!(<KittiesModule as Inspect<u64>>::attribute(&(), &1, b"name"), None);
+		assert!(<KittiesModule as Inspect<u64>>::can_transfer(&(), &1));
+
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 5));
+		assert!(!<KittiesModule as Inspect<u64>>::can_transfer(&(), &1));
+		assert!(!<KittiesModule as Inspect<u64>>::can_transfer(&(), &2));
+	});
+}
+
+//通过nonfungibles接口转移、铸造和销毁Kitty的用例
+#[test]
+fn nonfungibles_transfer_and_mutate_works() {
+	use frame_support::traits::tokens::nonfungibles::{Mutate, Transfer};
+
+	new_test_ext().execute_with(||{
+		assert_noop!(
+			<KittiesModule as Mutate<u64>>::mint_into(&(), &2, &1),
+			Error::<Test>::InvalidKittyIndex
+		);
+		assert_ok!(<KittiesModule as Mutate<u64>>::mint_into(&(), &1, &1));
+		assert_eq!(Owner::<Test>::get(1), Some(1));
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000);
+
+		assert_ok!(<KittiesModule as Transfer<u64>>::transfer(&(), &1, &2));
+		assert_eq!(Owner::<Test>::get(1), Some(2));
+		assert_eq!(Balances::reserved_balance(2), 1_000_000_000);
+		assert_noop!(
+			<KittiesModule as Transfer<u64>>::transfer(&(), &9, &2),
+			Error::<Test>::InvalidKittyIndex
+		);
+
+		assert_ok!(<KittiesModule as Mutate<u64>>::burn_from(&(), &1));
+		assert_eq!(Owner::<Test>::get(1), None);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(KittiesModule::total_supply(), 0);
+	});
+}
+
+//授权其他账户转移Kitty的用例，Kitty转移后授权被清除
+#[test]
+fn approve_and_transfer_from_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::approve(Origin::signed(1), 1, Some(2)));
+		assert_eq!(KittiesModule::approved(1), Some(2));
+		assert_eq!(last_event(), crate::mock::Event::KittiesModule(crate::Event::<Test>::Approval(1, 1, Some(2))));
+
+		assert_ok!(KittiesModule::transfer_from(Origin::signed(2), 1, 3, 1));
+		assert_eq!(Owner::<Test>::get(1), Some(3));
+		assert_eq!(KittiesModule::approved(1), None);
+		assert_noop!(
+			KittiesModule::transfer_from(Origin::signed(2), 3, 2, 1),
+			Error::<Test>::NotApproved
+		);
+
+		//取消授权
+		assert_ok!(KittiesModule::approve(Origin::signed(3), 1, Some(2)));
+		assert_ok!(KittiesModule::approve(Origin::signed(3), 1, None));
+		assert_eq!(KittiesModule::approved(1), None);
+	});
+}
+
+//操作员可以授权和转移拥有者所有Kitty的用例
+#[test]
+fn set_approval_for_all_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::set_approval_for_all(Origin::signed(1), 2, true));
+		assert!(KittiesModule::is_approved_for_all(1, 2));
+		assert_eq!(last_event(), crate::mock::Event::KittiesModule(crate::Event::<Test>::ApprovalForAll(1, 2, true)));
+
+		assert_ok!(KittiesModule::transfer_from(Origin::signed(2), 1, 2, 1));
+		assert_eq!(Owner::<Test>::get(1), Some(2));
+		assert_ok!(KittiesModule::approve(Origin::signed(2), 2, Some(3)));
+		assert_ok!(KittiesModule::transfer_from(Origin::signed(3), 1, 4, 2));
+		assert_eq!(Owner::<Test>::get(2), Some(4));
+
+		assert_ok!(KittiesModule::set_approval_for_all(Origin::signed(1), 2, false));
+		assert!(!KittiesModule::is_approved_for_all(1, 2));
+	});
+}
+
+//授权和授权转移的失败用例
+#[test]
+fn approve_and_transfer_from_failures() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::approve(Origin::signed(1), 9, Some(2)),
+			Error::<Test>::InvalidKittyIndex
+		);
+		assert_noop!(
+			KittiesModule::approve(Origin::signed(2), 1, Some(2)),
+			Error::<Test>::NotApproved
+		);
+		assert_noop!(
+			KittiesModule::approve(Origin::signed(1), 1, Some(1)),
+			Error::<Test>::ApproveToSelf
+		);
+		assert_noop!(
+			KittiesModule::set_approval_for_all(Origin::signed(1), 1, true),
+			Error::<Test>::ApproveToSelf
+		);
+		assert_noop!(
+			KittiesModule::transfer_from(Origin::signed(2), 2, 3, 1),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::transfer_from(Origin::signed(2), 1, 3, 1),
+			Error::<Test>::NotApproved
+		);
+
+		assert_ok!(KittiesModule::approve(Origin::signed(1), 1, Some(2)));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 5));
+		assert_noop!(
+			KittiesModule::transfer_from(Origin::signed(2), 1, 3, 1),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+//设置Kitty名字的成功用例：按字节质押，改名时更新名字索引，清除名字时退还质押
+#[test]
+fn set_name_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::set_name(Origin::signed(1), 1, b"tom".to_vec()));
+		assert_eq!(KittiesModule::metadata(1).map(|m| m.name.into_inner()), Some(b"tom".to_vec()));
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000 + 3 * 1_000_000);
+		assert_eq!(KittiesModule::kitty_deposit(1), 1_000_000_000 + 3 * 1_000_000);
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittyNamed(1, 1, b"tom".to_vec()))
+		);
+
+		assert_ok!(KittiesModule::set_name(Origin::signed(1), 1, b"jerry".to_vec()));
+		let jerry = KittyNameOf::<Test>::try_from(b"jerry".to_vec()).unwrap();
+		let tom = KittyNameOf::<Test>::try_from(b"tom".to_vec()).unwrap();
+		assert!(NameIndex::<Test>::contains_key(&jerry, 1));
+		assert!(!NameIndex::<Test>::contains_key(&tom, 1));
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000 + 5 * 1_000_000);
+
+		assert_ok!(KittiesModule::set_name(Origin::signed(1), 1, vec![]));
+		assert_eq!(KittiesModule::metadata(1), None);
+		assert!(!NameIndex::<Test>::contains_key(&jerry, 1));
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000);
+		assert_eq!(KittiesModule::kitty_deposit(1), 1_000_000_000);
+	});
+}
+
+//设置元数据的用例：名字和元数据的质押随Kitty转移，销毁时退还
+#[test]
+fn set_metadata_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::set_name(Origin::signed(1), 1, b"tom".to_vec()));
+		assert_ok!(KittiesModule::set_metadata(Origin::signed(1), 1, b"ipfs://kitty".to_vec()));
+		assert_eq!(
+			KittiesModule::metadata(1).map(|m| (m.name.into_inner(), m.metadata_uri.into_inner(), m.deposit)),
+			Some((b"tom".to_vec(), b"ipfs://kitty".to_vec(), 15 * 1_000_000))
+		);
+		assert_eq!(
+			last_event(),
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittyMetadataSet(1, 1, b"ipfs://kitty".to_vec()))
+		);
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 1_000_000_000 + 15 * 1_000_000);
+
+		assert_ok!(KittiesModule::burn(Origin::signed(2), 1));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(KittiesModule::metadata(1), None);
+		let tom = KittyNameOf::<Test>::try_from(b"tom".to_vec()).unwrap();
+		assert!(!NameIndex::<Test>::contains_key(&tom, 1));
+	});
+}
+
+//设置名字和元数据的失败用例
+#[test]
+fn set_name_and_metadata_failures() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::set_name(Origin::signed(2), 1, b"tom".to_vec()),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::set_metadata(Origin::signed(2), 1, b"ipfs://kitty".to_vec()),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::set_name(Origin::signed(1), 1, b"too long!".to_vec()),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			KittiesModule::set_metadata(Origin::signed(1), 1, b"ipfs://too-long-uri".to_vec()),
+			Error::<Test>::MetadataTooLong
+		);
+
+		let free = Balances::free_balance(1);
+		assert_ok!(Balances::reserve(&1, free - 1));
+		assert_noop!(
+			KittiesModule::set_name(Origin::signed(1), 1, b"tom".to_vec()),
+			Error::<Test>::MoneyIsNotEnough
+		);
+	});
+}
+
+//名字唯一时重名的失败用例，允许重名时的成功用例
+#[test]
+fn set_name_when_name_is_taken() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::set_name(Origin::signed(1), 1, b"tom".to_vec()));
+		//同一只Kitty重新设置相同的名字
+		assert_ok!(KittiesModule::set_name(Origin::signed(1), 1, b"tom".to_vec()));
+		assert_noop!(
+			KittiesModule::set_name(Origin::signed(2), 2, b"tom".to_vec()),
+			Error::<Test>::NameTaken
+		);
+
+		set_unique_names(false);
+		assert_ok!(KittiesModule::set_name(Origin::signed(2), 2, b"tom".to_vec()));
+		let tom = KittyNameOf::<Test>::try_from(b"tom".to_vec()).unwrap();
+		assert!(NameIndex::<Test>::contains_key(&tom, 1));
+		assert!(NameIndex::<Test>::contains_key(&tom, 2));
+		set_unique_names(true);
+	});
+}
+
+//出售中的Kitty：转移时自动取消出售，不能繁殖、拍卖或出租繁殖
+#[test]
+fn listed_kitty_locks() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000));
+		assert_eq!(KittiesModule::locks_of(1), vec![KittyLock::Listed]);
+
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::KittyIsOnSale
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 2, 1),
+			Error::<Test>::KittyIsOnSale
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10),
+			Error::<Test>::KittyIsOnSale
+		);
+		assert_noop!(
+			KittiesModule::offer_siring(Origin::signed(1), 1, 5_000_000_000),
+			Error::<Test>::KittyIsOnSale
+		);
+		//出售中可以更新售价
+		assert_ok!(KittiesModule::sell_kitty(Origin::signed(1), 1, 20_000_000_000));
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+		assert_eq!(KittiesModule::kitties_price(1), None);
+		assert!(KittiesModule::locks_of(1).is_empty());
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::Event::KittiesModule(crate::Event::<Test>::KittySaleCancelled(1, 1))));
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(3), 1, 20_000_000_000),
+			Error::<Test>::KittyIsNotOnSale
+		);
+	});
+}
+
+//拍卖中的Kitty：不能转移、出售、繁殖、出租繁殖、接受报价或被购买
+#[test]
+fn auctioning_kitty_locks() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 10_000_000_000, 10));
+		assert_ok!(KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10));
+		assert_eq!(KittiesModule::locks_of(1), vec![KittyLock::Auctioning]);
+
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 2, 1),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::offer_siring(Origin::signed(1), 1, 5_000_000_000),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(1), 1, 2),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10),
+			Error::<Test>::KittyInAuction
+		);
+
+		//升级前遗留的出售信息也不能绕过拍卖
+		KittiesPrice::<Test>::insert(1, Some(10_000_000_000));
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(3), 1, 10_000_000_000),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+//出租繁殖中的Kitty：可以繁殖和转移（转移时取消出租），不能出售或拍卖
+#[test]
+fn siring_kitty_locks() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(1), 1, 5_000_000_000));
+		assert_eq!(KittiesModule::locks_of(1), vec![KittyLock::Siring]);
+
+		assert_noop!(
+			KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000),
+			Error::<Test>::KittyIsSiring
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10),
+			Error::<Test>::KittyIsSiring
+		);
+		//出租中可以更新配种费
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(1), 1, 6_000_000_000));
+
+		assert_ok!(KittiesModule::breed_with_sire(Origin::signed(2), 3, 1, 6_000_000_000));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 10_000_000_000, 10));
+		assert_ok!(KittiesModule::accept_offer(Origin::signed(1), 1, 2));
+		assert_eq!(KittiesModule::siring_offer(1), None);
+	});
+}
+
+//怀孕中的Kitty：不能转移、出售、拍卖、出租繁殖、接受报价、销毁或再次繁殖
+#[test]
+fn gestating_kitty_locks() {
+	new_test_ext().execute_with(||{
+		set_gestation_period(5);
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 1, 10_000_000_000, 10));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_eq!(KittiesModule::locks_of(1), vec![KittyLock::Gestating]);
+		//父方不受影响
+		assert!(KittiesModule::locks_of(2).is_empty());
+
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 2, 1),
+			Error::<Test>::KittyIsPregnant
+		);
+		assert_noop!(
+			KittiesModule::sell_kitty(Origin::signed(1), 1, 10_000_000_000),
+			Error::<Test>::KittyIsPregnant
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 1, english(10_000_000_000), 10),
+			Error::<Test>::KittyIsPregnant
+		);
+		assert_noop!(
+			KittiesModule::offer_siring(Origin::signed(1), 1, 5_000_000_000),
+			Error::<Test>::KittyIsPregnant
+		);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(1), 1, 2),
+			Error::<Test>::KittyIsPregnant
+		);
+		assert_noop!(
+			KittiesModule::burn(Origin::signed(1), 1),
+			Error::<Test>::KittyIsPregnant
+		);
+
+		System::set_block_number(6);
+		KittiesModule::on_initialize(6);
+		assert!(KittiesModule::locks_of(1).is_empty());
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+		set_gestation_period(0);
+	});
+}
+
+//供应统计的成功用例：铸造、销毁和各代现存数量分别统计，KittyIndex不随销毁回退
+#[test]
+fn supply_stats_works() {
+	new_test_ext().execute_with(||{
+		assert_eq!(KittiesModule::supply_stats(), SupplyStats::default());
+
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 1, 2));
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 1));
+
+		assert_eq!(KittiesModule::supply_stats(), SupplyStats {
+			minted: 3,
+			burned: 1,
+			live: 2,
+			per_generation: vec![(0, 1), (1, 1)],
+		});
+
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 3));
+		assert_eq!(KittiesModule::supply_stats().per_generation, vec![(0, 1)]);
+		assert_eq!(KittiesModule::next_id(), 4);
+
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(Owner::<Test>::get(4), Some(1));
+		assert_eq!(KittiesModule::supply_stats().minted, 4);
+	});
+}
+
+//KittiesCount迁移为NextKittyId的成功用例：根据现存的Kitty补齐供应统计，重复执行不做修改
+#[test]
+fn migrate_to_next_kitty_id_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 2));
+
+		//还原为迁移前的存储布局，Kitty 1是没有血统记录、代数字节为随机值的旧Kitty
+		Lineages::<Test>::remove(1);
+		Kitties::<Test>::insert(1, Some(Kitty([0xff; 16])));
+		NextKittyId::<Test>::kill();
+		KittiesMinted::<Test>::kill();
+		KittiesBurned::<Test>::kill();
+		GenerationSupply::<Test>::remove(0);
+		frame_support::storage::migration::put_storage_value(b"KittiesModule", b"KittiesCount", &[], 4u32);
+
+		crate::migrations::migrate_to_next_kitty_id::<Test>();
+
+		assert_eq!(KittiesModule::next_id(), 4);
+		assert_eq!(KittiesModule::supply_stats(), SupplyStats {
+			minted: 3,
+			burned: 1,
+			live: 2,
+			per_generation: vec![(0, 2)],
+		});
+		assert_eq!(
+			frame_support::storage::migration::get_storage_value::<u32>(b"KittiesModule", b"KittiesCount", &[]),
+			None
+		);
+
+		assert_eq!(KittiesModule::lineage(1).map(|lineage| lineage.generation), Some(0));
+		assert_eq!(KittiesModule::decode_traits(1).map(|traits| traits.generation), Some(0));
+
+		crate::migrations::migrate_to_next_kitty_id::<Test>();
+		assert_eq!(KittiesModule::supply_stats().minted, 3);
+
+		//销毁旧Kitty时同步减少其代数的现存数量
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 1));
+		assert_eq!(KittiesModule::supply_stats().per_generation, vec![(0, 1)]);
+
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(Owner::<Test>::get(4), Some(1));
+	});
+}
+
+//为旧Kitty补记质押的成功用例：按拥有者实际质押的金额记入，补记后销毁可以退还质押
+#[test]
+fn migrate_kitty_deposits_works() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+
+		//还原为迁移前的存储布局：没有记录质押，且拥有者只为一只Kitty质押
+		KittyDeposits::<Test>::remove(1);
+		KittyDeposits::<Test>::remove(2);
+		Balances::unreserve(&1, 1_000_000_000);
+
+		crate::migrations::migrate_kitty_deposits::<Test>();
+
+		assert_eq!(KittiesModule::kitty_deposit(1) + KittiesModule::kitty_deposit(2), 1_000_000_000);
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 1));
+		assert_ok!(KittiesModule::burn(Origin::signed(1), 2));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+//旧版转移后质押留在铸造者上的迁移用例：补记当前拥有者实际质押的金额，退还铸造者多余的质押
+#[test]
+fn migrate_kitty_deposits_unreserves_former_owner() {
+	new_test_ext().execute_with(||{
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 1));
+
+		//还原为迁移前的存储布局：Kitty 1由账户1铸造后转给账户2，质押仍留在账户1上
+		KittyDeposits::<Test>::remove(1);
+		KittyDeposits::<Test>::remove(2);
+		Balances::unreserve(&2, 1_000_000_000);
+		assert_ok!(Balances::reserve(&1, 1_000_000_000));
+		let free = Balances::free_balance(1);
+
+		crate::migrations::migrate_kitty_deposits::<Test>();
+
+		assert_eq!(KittiesModule::kitty_deposit(1), 0);
+		assert_eq!(KittiesModule::kitty_deposit(2), 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(1), 1_000_000_000);
+		assert_eq!(Balances::free_balance(1), free + 1_000_000_000);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+//从旧存储布局升级时重建OwnedKitties和创作者的用例：旧Kitty计入拥有上限
+#[test]
+fn migrate_owned_kitties_works() {
+	new_test_ext().execute_with(||{
+		//旧存储布局：只有Kitties、Owner和KittiesCount，拥有者为每只Kitty质押
+		for (kitty_id, owner) in vec![(1u32, 1u64), (2, 1), (3, 2)] {
+			Kitties::<Test>::insert(kitty_id, Some(Kitty([0; 16])));
+			Owner::<Test>::insert(kitty_id, Some(owner));
+			assert_ok!(Balances::reserve(&owner, 1_000_000_000));
+		}
+		NextKittyId::<Test>::kill();
+		frame_support::storage::migration::put_storage_value(b"KittiesModule", b"KittiesCount", &[], 4u32);
+
+		KittiesModule::on_runtime_upgrade();
+
+		let mut owned = KittiesModule::owned_kitties(1).into_inner();
+		owned.sort();
+		assert_eq!(owned, vec![1, 2]);
+		assert_eq!(KittiesModule::owned_kitties(2).into_inner(), vec![3]);
+		assert_eq!(KittiesModule::kitty_creator(1), Some(1));
+		assert_eq!(KittiesModule::kitty_creator(3), Some(2));
+
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::create(Origin::signed(1)),
+			Error::<Test>::ExceedMaxKittiesOwned
+		);
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(2), 1, 3),
+			Error::<Test>::ExceedMaxKittiesOwned
+		);
+	});
+}
@@ -0,0 +1,74 @@
+//! RPC methods for the proof of existence pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_poe_runtime_api::{ClaimDigest, MerkleProofNode, PoeApi as PoeRuntimeApi};
+
+/// Proof of existence RPC methods.
+#[rpc]
+pub trait PoeApi<BlockHash, BlockNumber, AccountId> {
+	/// Verify that `leaf` is included under the anchored Merkle `root` via `path`.
+	///
+	/// Leaves are hashed as `H(0x00 ++ leaf)` and internal nodes as `H(0x01 ++ left ++ right)`
+	/// with the root's hash algorithm. Returns the anchoring block and the owner of the root, or
+	/// `None` if the root is not anchored, the path is longer than the tree height or the proof
+	/// does not match.
+	#[rpc(name = "poe_verifyInclusion")]
+	fn verify_inclusion(
+		&self,
+		leaf: [u8; 32],
+		path: Vec<MerkleProofNode>,
+		root: ClaimDigest,
+		at: Option<BlockHash>,
+	) -> Result<Option<(BlockNumber, AccountId)>>;
+}
+
+/// Implements the [`PoeApi`] RPC trait by calling into the runtime.
+pub struct Poe<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Poe<C, B> {
+	/// Create a new `Poe` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Poe { client, _marker: Default::default() }
+	}
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i64 = 1;
+
+impl<C, Block, BlockNumber, AccountId> PoeApi<<Block as BlockT>::Hash, BlockNumber, AccountId>
+	for Poe<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber>,
+	BlockNumber: Codec,
+	AccountId: Codec,
+{
+	fn verify_inclusion(
+		&self,
+		leaf: [u8; 32],
+		path: Vec<MerkleProofNode>,
+		root: ClaimDigest,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<(BlockNumber, AccountId)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.verify_inclusion(&at, leaf, path, root).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(RUNTIME_ERROR),
+			message: "Unable to verify inclusion proof.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
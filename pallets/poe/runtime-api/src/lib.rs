@@ -0,0 +1,31 @@
+//! Runtime API definition for the proof of existence pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+pub use pallet_poe::{ClaimDigest, HashAlgorithm, MerkleProofNode};
+
+sp_api::decl_runtime_apis! {
+	pub trait PoeApi<AccountId, BlockNumber> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Hash `preimage` with `algorithm` and return the owner and block of the matching
+		/// proof, if one is registered.
+		fn verify_preimage(algorithm: HashAlgorithm, preimage: Vec<u8>) -> Option<(AccountId, BlockNumber)>;
+
+		/// All claims currently owned by `account`.
+		fn claims_of(account: AccountId) -> Vec<ClaimDigest>;
+
+		/// Check that `leaf` is included under the anchored Merkle `root` via `path`, returning
+		/// the anchoring block and the current owner of the root. Leaves are hashed as
+		/// `H(0x00 ++ leaf)` and internal nodes as `H(0x01 ++ left ++ right)`; paths longer
+		/// than the height of the anchored tree are rejected.
+		fn verify_inclusion(
+			leaf: [u8; 32],
+			path: Vec<MerkleProofNode>,
+			root: ClaimDigest,
+		) -> Option<(BlockNumber, AccountId)>;
+	}
+}
@@ -0,0 +1,112 @@
+//! Benchmarking setup for pallet-poe
+
+use super::*;
+
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, impl_benchmark_test_suite};
+use frame_support::{sp_runtime::traits::Bounded, traits::Currency};
+use sp_std::{vec, vec::Vec};
+#[allow(unused)]
+use crate::Pallet as Poe;
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	caller
+}
+
+fn claims<T: Config>(n: u32) -> Vec<ClaimDigest> {
+	(0..n).map(|i| ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, &i.to_le_bytes())).collect()
+}
+
+fn create_claim_with_memo<T: Config>(owner: &T::AccountId, claim: ClaimDigest, m: u32) -> Result<(), &'static str> {
+	Poe::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), claim, vec![0u8; m as usize])?;
+	Ok(())
+}
+
+benchmarks! {
+	create_claim {
+		let m in 0 .. T::MaxMemoLength::get();
+		let caller = funded_caller::<T>();
+		let claim = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"document");
+	}: _(RawOrigin::Signed(caller.clone()), claim, vec![0u8; m as usize])
+	verify {
+		assert_eq!(Proofs::<T>::get(&claim).map(|record| record.owner), Some(caller));
+	}
+
+	revoke_claim {
+		let caller = funded_caller::<T>();
+		let claim = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"document");
+		create_claim_with_memo::<T>(&caller, claim, T::MaxMemoLength::get())?;
+	}: _(RawOrigin::Signed(caller.clone()), claim)
+	verify {
+		assert!(!Proofs::<T>::contains_key(&claim));
+	}
+
+	create_claims {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller = funded_caller::<T>();
+		let claims = claims::<T>(n);
+	}: _(RawOrigin::Signed(caller.clone()), claims)
+	verify {
+		assert_eq!(ClaimsOf::<T>::get(&caller).len() as u32, n);
+	}
+
+	revoke_claims {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller = funded_caller::<T>();
+		let claims = claims::<T>(n);
+		Poe::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), claims.clone())?;
+	}: _(RawOrigin::Signed(caller.clone()), claims)
+	verify {
+		assert!(ClaimsOf::<T>::get(&caller).is_empty());
+	}
+
+	anchor_merkle_root {
+		let m in 0 .. T::MaxMemoLength::get();
+		let caller = funded_caller::<T>();
+		let root = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"root");
+	}: _(RawOrigin::Signed(caller.clone()), root, 1024, vec![0u8; m as usize])
+	verify {
+		assert_eq!(MerkleRoots::<T>::get(&root), Some(1024));
+	}
+
+	transfer_claim {
+		let caller = funded_caller::<T>();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		let claim = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"document");
+		create_claim_with_memo::<T>(&caller, claim, 0)?;
+	}: _(RawOrigin::Signed(caller), claim, receiver.clone())
+	verify {
+		assert_eq!(PendingTransfers::<T>::get(&claim).map(|(to, _)| to), Some(receiver));
+	}
+
+	accept_claim {
+		let caller = funded_caller::<T>();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		T::Currency::make_free_balance_be(&receiver, T::Currency::minimum_balance());
+		let claim = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"document");
+		create_claim_with_memo::<T>(&caller, claim, T::MaxMemoLength::get())?;
+		Poe::<T>::transfer_claim(RawOrigin::Signed(caller).into(), claim, receiver.clone())?;
+	}: _(RawOrigin::Signed(receiver.clone()), claim)
+	verify {
+		assert_eq!(Proofs::<T>::get(&claim).map(|record| record.owner), Some(receiver));
+	}
+
+	cancel_claim_transfer {
+		let caller = funded_caller::<T>();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		let claim = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"document");
+		create_claim_with_memo::<T>(&caller, claim, 0)?;
+		Poe::<T>::transfer_claim(RawOrigin::Signed(caller.clone()).into(), claim, receiver)?;
+	}: _(RawOrigin::Signed(caller), claim)
+	verify {
+		assert!(!PendingTransfers::<T>::contains_key(&claim));
+	}
+}
+
+impl_benchmark_test_suite!(
+	Poe,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
@@ -8,23 +8,142 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+
 //定义功能模块
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
-        dispatch::DispatchResultWithPostInfo, 
-        pallet_prelude::*
+        dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo}, 
+        pallet_prelude::*,
+        sp_runtime::traits::{Saturating, Zero},
+        storage::bounded_vec::BoundedVec,
+        traits::{BalanceStatus, Currency, ReservableCurrency},
+        transactional,
     };
     use frame_system::pallet_prelude::*;
-    use sp_std::vec::Vec; 
+    use crate::weights::WeightInfo;
+    use sp_std::{convert::TryFrom, vec::Vec};
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
+
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    //存证摘要使用的哈希算法
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum HashAlgorithm {
+        Blake2_256,
+        Sha2_256,
+        Keccak256,
+    }
+
+    impl HashAlgorithm {
+        //用该算法计算原文的32字节摘要
+        pub fn hash(&self, preimage: &[u8]) -> [u8; 32] {
+            match self {
+                HashAlgorithm::Blake2_256 => sp_io::hashing::blake2_256(preimage),
+                HashAlgorithm::Sha2_256 => sp_io::hashing::sha2_256(preimage),
+                HashAlgorithm::Keccak256 => sp_io::hashing::keccak_256(preimage),
+            }
+        }
+    }
+
+    //带算法标签的定长存证摘要，作为Proofs的键
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ClaimDigest {
+        pub algorithm: HashAlgorithm,
+        pub digest: [u8; 32],
+    }
+
+    impl ClaimDigest {
+        //由文档原文计算存证摘要
+        pub fn from_preimage(algorithm: HashAlgorithm, preimage: &[u8]) -> Self {
+            ClaimDigest {
+                algorithm,
+                digest: algorithm.hash(preimage),
+            }
+        }
+    }
+    
+    //Merkle树叶子节点和内部节点的哈希前缀，防止内部节点被当作叶子通过校验
+    pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+    pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+    //Merkle证明路径上的兄弟节点，标明它位于左侧还是右侧
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum MerkleProofNode {
+        Left([u8; 32]),
+        Right([u8; 32]),
+    }
+
+    //存证记录
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+    pub struct ClaimRecord<AccountId, BlockNumber, Moment, Balance, Memo, History> {
+        //最初创建者
+        pub creator: AccountId,
+        //当前拥有者
+        pub owner: AccountId,
+        //创建区块
+        pub created_at: BlockNumber,
+        //创建时间戳
+        pub created_time: Moment,
+        //最近一次转移的区块
+        pub last_transferred_at: Option<BlockNumber>,
+        //拥有者质押的押金
+        pub deposit: Balance,
+        //备注或文档URI
+        pub memo: Memo,
+        //最近的转移记录(new_owner, block)，超过上限时丢弃最早的记录
+        pub history: History,
+    }
+
+    pub type MemoOf<T> = BoundedVec<u8, <T as Config>::MaxMemoLength>;
+
+    pub type HistoryOf<T> = BoundedVec<
+        (<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber),
+        <T as Config>::MaxTransferHistory,
+    >;
+
+    pub type ClaimRecordOf<T> = ClaimRecord<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::BlockNumber,
+        <T as pallet_timestamp::Config>::Moment,
+        BalanceOf<T>,
+        MemoOf<T>,
+        HistoryOf<T>,
+    >;
     
     //定义配置接口
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + pallet_timestamp::Config {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-        type ClaimSize: Get<usize>;
+        //每个账户最多拥有的存证数量
+        type MaxClaimsPerAccount: Get<u32>;
+        //用于质押存证押金的代币
+        type Currency: ReservableCurrency<Self::AccountId>;
+        //每个存证的基础押金
+        type ClaimDepositBase: Get<BalanceOf<Self>>;
+        //存证每字节的押金
+        type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+        //存证转移请求的有效区块数，超过后接收者无法再接受
+        type TransferExpiry: Get<Self::BlockNumber>;
+        //备注的最大字节数
+        type MaxMemoLength: Get<u32>;
+        //保留的转移记录条数
+        type MaxTransferHistory: Get<u32>;
+        //批量创建或撤销存证的最大数量
+        type MaxBatchSize: Get<u32>;
+        //Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -37,18 +156,62 @@ pub mod pallet {
     pub type Proofs<T: Config> = StorageMap<
         _, 
         Blake2_128Concat, 
-        Vec<u8>, 
-        (T::AccountId, T::BlockNumber)
+        ClaimDigest, 
+        ClaimRecordOf<T>
     >;   
 
+    //待接收的存证转移：(receiver, expires_at)
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfers)]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimDigest,
+        (T::AccountId, T::BlockNumber)
+    >;
+
+    //账户拥有的存证索引
+    #[pallet::storage]
+    #[pallet::getter(fn claims_of)]
+    pub type ClaimsOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ClaimDigest, T::MaxClaimsPerAccount>,
+        ValueQuery
+    >;
+
+    //已锚定的Merkle根及其覆盖的文档数量
+    #[pallet::storage]
+    #[pallet::getter(fn merkle_roots)]
+    pub type MerkleRoots<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimDigest,
+        u32
+    >;
+
     //定义事件
     #[pallet::event]
-    #[pallet::metadata(T::AccountId = "AccountId")]
+    #[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config>{
-        ClaimCreated(T::AccountId, Vec<u8>),
-        ClaimRevoked(T::AccountId, Vec<u8>),
-        ClaimTransferred(T::AccountId, Vec<u8>, T::AccountId),
+        //ClaimCreated(owner, claim, reserved_deposit)
+        ClaimCreated(T::AccountId, ClaimDigest, BalanceOf<T>),
+        //ClaimsCreated(owner, count, total_reserved_deposit)
+        ClaimsCreated(T::AccountId, u32, BalanceOf<T>),
+        //ClaimRevoked(owner, claim, unreserved_deposit)
+        ClaimRevoked(T::AccountId, ClaimDigest, BalanceOf<T>),
+        //ClaimsRevoked(owner, count, total_unreserved_deposit)
+        ClaimsRevoked(T::AccountId, u32, BalanceOf<T>),
+        //MerkleRootAnchored(owner, root, leaf_count)
+        MerkleRootAnchored(T::AccountId, ClaimDigest, u32),
+        //ClaimTransferOffered(owner, claim, receiver, expires_at)
+        ClaimTransferOffered(T::AccountId, ClaimDigest, T::AccountId, T::BlockNumber),
+        //ClaimTransferCancelled(owner, claim)
+        ClaimTransferCancelled(T::AccountId, ClaimDigest),
+        //ClaimTransferred(owner, claim, receiver, repatriated_deposit)
+        ClaimTransferred(T::AccountId, ClaimDigest, T::AccountId, BalanceOf<T>),
     }
     
     //定义错误信息
@@ -57,98 +220,413 @@ pub mod pallet {
         ProofAlreadyExist,
         ClaimNotExist,
         NotProofOwner,
-        ClaimSizeTooLarge,
+        //账户拥有的存证数量达到上限
+        TooManyClaims,
+        //余额不足以质押存证押金
+        InsufficientBalance,
+        //不能把存证转移给自己
+        TransferToSelf,
+        //存证没有待接收的转移请求
+        NoPendingTransfer,
+        //调用者不是转移请求的接收者
+        NotTransferReceiver,
+        //转移请求已过期
+        TransferExpired,
+        //备注超过最大长度
+        MemoTooLong,
+        //批量操作的存证数量超过上限
+        BatchTooLarge,
+        //Merkle树没有叶子
+        EmptyMerkleTree,
+    }
+
+    //创世区块中预先登记的存证：(拥有者, 存证摘要)，拥有者需要质押存证押金
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub claims: Vec<(T::AccountId, ClaimDigest)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { claims: Vec::new() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for (owner, claim) in self.claims.iter() {
+                Pallet::<T>::do_create_claim(owner, *claim, Default::default())
+                    .expect("genesis claims should be unique and their owners able to pay the deposit");
+            }
+        }
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            crate::migrations::migrate_raw_claim_keys::<T>()
+                .saturating_add(crate::migrations::migrate_to_claim_record::<T>())
+        }
+    }
     
     //定义可调用函数
     #[pallet::call]
     impl<T: Config> Pallet<T>{
         //创建存证
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::create_claim(memo.len() as u32))]
+        #[transactional]
         pub fn create_claim(
             origin: OriginFor<T>,
-            claim: Vec<u8>,
+            claim: ClaimDigest,
+            memo: Vec<u8>,
         ) -> DispatchResultWithPostInfo {
 
             let sender = ensure_signed(origin)?;
-             
-            ensure!(!Proofs::<T>::contains_key(&claim), Error::<T>::ProofAlreadyExist);
-
-            //检查存证内容大小，是否小于ClaimSize上限
-            ensure!(claim.len() <= T::ClaimSize::get(), Error::<T>::ClaimSizeTooLarge);
-
-            // Get the block number from the FRAME System module.
-            let current_block = <frame_system::Pallet<T>>::block_number();
 
-            // Store the claim with the sender and block number.
-            Proofs::<T>::insert(
-                &claim, 
-                (sender.clone(), current_block)
-            );
+            let memo = MemoOf::<T>::try_from(memo).map_err(|_| Error::<T>::MemoTooLong)?;
 
-            // Emit an event that the claim was created.
-            Self::deposit_event(Event::ClaimCreated(sender, claim));
+            Self::do_create_claim(&sender, claim, memo)?;
 
             Ok(().into())
         }
         
         //撤销存证
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::revoke_claim())]
         pub fn revoke_claim(
             origin: OriginFor<T>,
-            claim: Vec<u8>,
+            claim: ClaimDigest,
         ) -> DispatchResultWithPostInfo {
             
             let sender = ensure_signed(origin)?;
 
-            // Get owner of the claim.
-            let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+            Self::do_revoke_claim(&sender, claim)?;
 
-            // Verify that sender of the current call is the claim owner.
-            ensure!(sender == owner, Error::<T>::NotProofOwner);
+            Ok(().into())
+        }
+        
 
-            // Remove claim from storage.
-            Proofs::<T>::remove(&claim);
+        //批量创建存证，任一存证无法创建时全部回滚
+        #[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+        #[transactional]
+        pub fn create_claims(
+            origin: OriginFor<T>,
+            claims: Vec<ClaimDigest>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
 
-            // Emit an event that the claim was erased.
-            Self::deposit_event(Event::ClaimRevoked(sender, claim));
+            ensure!(claims.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+            let mut total_deposit = BalanceOf::<T>::zero();
+            for claim in claims.iter() {
+                let deposit = Self::do_create_claim(&sender, *claim, Default::default())?;
+                total_deposit = total_deposit.saturating_add(deposit);
+            }
+
+            Self::deposit_event(Event::ClaimsCreated(sender, claims.len() as u32, total_deposit));
 
             Ok(().into())
         }
-        
 
-        //转移存证
-        #[pallet::weight(0)]
+        //批量撤销存证，任一存证无法撤销时全部回滚
+        #[pallet::weight(T::WeightInfo::revoke_claims(claims.len() as u32))]
+        #[transactional]
+        pub fn revoke_claims(
+            origin: OriginFor<T>,
+            claims: Vec<ClaimDigest>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(claims.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+            let mut total_deposit = BalanceOf::<T>::zero();
+            for claim in claims.iter() {
+                let deposit = Self::do_revoke_claim(&sender, *claim)?;
+                total_deposit = total_deposit.saturating_add(deposit);
+            }
+
+            Self::deposit_event(Event::ClaimsRevoked(sender, claims.len() as u32, total_deposit));
+
+            Ok(().into())
+        }
+
+        //锚定Merkle根，用一个存证覆盖多份文档
+        #[pallet::weight(T::WeightInfo::anchor_merkle_root(memo.len() as u32))]
+        #[transactional]
+        pub fn anchor_merkle_root(
+            origin: OriginFor<T>,
+            root: ClaimDigest,
+            leaf_count: u32,
+            memo: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(leaf_count > 0, Error::<T>::EmptyMerkleTree);
+
+            let memo = MemoOf::<T>::try_from(memo).map_err(|_| Error::<T>::MemoTooLong)?;
+
+            Self::do_create_claim(&sender, root, memo)?;
+            MerkleRoots::<T>::insert(&root, leaf_count);
+
+            Self::deposit_event(Event::MerkleRootAnchored(sender, root, leaf_count));
+
+            Ok(().into())
+        }
+
+        //发起存证转移，需接收者接受后才生效
+        #[pallet::weight(T::WeightInfo::transfer_claim())]
         pub fn transfer_claim(
             origin: OriginFor<T>,
-            claim: Vec<u8>,
+            claim: ClaimDigest,
             receiver: T::AccountId,
         )-> DispatchResultWithPostInfo{
             //判断身份是否有效
             let sender = ensure_signed(origin)?;
         
             //查询存证拥有者
-            let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+            let owner = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
 
             //判断调用者是否是存证拥有者
             ensure!(sender == owner, Error::<T>::NotProofOwner);
+            ensure!(receiver != owner, Error::<T>::TransferToSelf);
+
+            //记录转移请求，覆盖之前未完成的请求
+            let expires_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::TransferExpiry::get());
+            PendingTransfers::<T>::insert(&claim, (receiver.clone(), expires_at));
+
+            Self::deposit_event(Event::ClaimTransferOffered(owner, claim, receiver, expires_at));
+
+            Ok(().into())
+        }
+
+        //接收者接受存证转移
+        #[pallet::weight(T::WeightInfo::accept_claim())]
+        #[transactional]
+        pub fn accept_claim(
+            origin: OriginFor<T>,
+            claim: ClaimDigest,
+        ) -> DispatchResultWithPostInfo {
+            let receiver = ensure_signed(origin)?;
+
+            let (pending_receiver, expires_at) = PendingTransfers::<T>::get(&claim)
+                .ok_or(Error::<T>::NoPendingTransfer)?;
+            ensure!(receiver == pending_receiver, Error::<T>::NotTransferReceiver);
 
             //得到当前区块高度
             let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block <= expires_at, Error::<T>::TransferExpired);
+
+            let mut record = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+            let owner = record.owner.clone();
+            let deposit = record.deposit;
+
+            //更新双方的存证索引
+            Self::add_to_index(&receiver, claim)?;
+            Self::remove_from_index(&owner, &claim);
+
+            //押金随存证转移给接收者
+            let remaining = T::Currency::repatriate_reserved(
+                &owner,
+                &receiver,
+                deposit,
+                BalanceStatus::Reserved,
+            )?;
+            ensure!(remaining.is_zero(), Error::<T>::InsufficientBalance);
+
+            //保留创建区块，记录本次转移区块
+            record.owner = receiver.clone();
+            record.last_transferred_at = Some(current_block);
+            Self::push_history(&mut record.history, (receiver.clone(), current_block));
+            Proofs::<T>::insert(&claim, record);
+            PendingTransfers::<T>::remove(&claim);
+
+            //触发事件ClaimTransferred
+            Self::deposit_event(Event::ClaimTransferred(owner, claim, receiver, deposit));
+
+            Ok(().into())
+        }
+
+        //拥有者取消存证转移
+        #[pallet::weight(T::WeightInfo::cancel_claim_transfer())]
+        pub fn cancel_claim_transfer(
+            origin: OriginFor<T>,
+            claim: ClaimDigest,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let owner = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+            ensure!(sender == owner, Error::<T>::NotProofOwner);
+            ensure!(PendingTransfers::<T>::contains_key(&claim), Error::<T>::NoPendingTransfer);
+
+            PendingTransfers::<T>::remove(&claim);
+
+            Self::deposit_event(Event::ClaimTransferCancelled(owner, claim));
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        //用文档原文校验存证，存在时返回存证拥有者和区块高度
+        pub fn verify_preimage(
+            algorithm: HashAlgorithm,
+            preimage: &[u8],
+        ) -> Option<(T::AccountId, T::BlockNumber)> {
+            Proofs::<T>::get(ClaimDigest::from_preimage(algorithm, preimage))
+                .map(|record| (record.owner, record.created_at))
+        }
+
+        //Merkle叶子节点：0x00前缀加文档摘要的哈希，与内部节点区分
+        pub fn hash_merkle_leaf(algorithm: HashAlgorithm, leaf: &[u8; 32]) -> [u8; 32] {
+            let mut data = [0u8; 33];
+            data[0] = MERKLE_LEAF_PREFIX;
+            data[1..].copy_from_slice(leaf);
+            algorithm.hash(&data)
+        }
+
+        //Merkle内部节点：0x01前缀加左右子节点的哈希
+        pub fn hash_merkle_node(algorithm: HashAlgorithm, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+            let mut data = [0u8; 65];
+            data[0] = MERKLE_NODE_PREFIX;
+            data[1..33].copy_from_slice(left);
+            data[33..].copy_from_slice(right);
+            algorithm.hash(&data)
+        }
+
+        //用根的哈希算法，由文档摘要和证明路径逐层计算Merkle根
+        pub fn compute_merkle_root(
+            algorithm: HashAlgorithm,
+            leaf: [u8; 32],
+            path: &[MerkleProofNode],
+        ) -> [u8; 32] {
+            path.iter().fold(Self::hash_merkle_leaf(algorithm, &leaf), |node, sibling| {
+                match sibling {
+                    MerkleProofNode::Left(hash) => Self::hash_merkle_node(algorithm, hash, &node),
+                    MerkleProofNode::Right(hash) => Self::hash_merkle_node(algorithm, &node, hash),
+                }
+            })
+        }
+
+        //校验文档摘要是否包含在已锚定的Merkle根中，返回锚定区块和拥有者
+        //证明路径不能长于leaf_count个叶子的树高ceil(log2(leaf_count))
+        pub fn verify_inclusion(
+            leaf: [u8; 32],
+            path: &[MerkleProofNode],
+            root: ClaimDigest,
+        ) -> Option<(T::BlockNumber, T::AccountId)> {
+            let leaf_count = MerkleRoots::<T>::get(&root)?;
+            let max_depth = 32 - leaf_count.saturating_sub(1).leading_zeros();
+            if path.len() as u32 > max_depth {
+                return None;
+            }
+            if Self::compute_merkle_root(root.algorithm, leaf, path) != root.digest {
+                return None;
+            }
+            Proofs::<T>::get(&root).map(|record| (record.created_at, record.owner))
+        }
+
+        //存证所需押金：基础押金加上按存证和备注长度计算的押金
+        pub fn claim_deposit(claim: &ClaimDigest, memo: &[u8]) -> BalanceOf<T> {
+            let bytes = (claim.encoded_size() + memo.len()) as u32;
+            T::ClaimDepositPerByte::get()
+                .saturating_mul(bytes.into())
+                .saturating_add(T::ClaimDepositBase::get())
+        }
+
+        //创建单个存证，返回质押的押金
+        fn do_create_claim(
+            sender: &T::AccountId,
+            claim: ClaimDigest,
+            memo: MemoOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            ensure!(!Proofs::<T>::contains_key(&claim), Error::<T>::ProofAlreadyExist);
+
+            // Get the block number from the FRAME System module.
+            let current_block = <frame_system::Pallet<T>>::block_number();
+
+            //加入账户存证索引
+            Self::add_to_index(sender, claim)?;
 
-            // 覆盖origin的value
+            //质押存证押金
+            let deposit = Self::claim_deposit(&claim, &memo);
+            T::Currency::reserve(sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            // Store the claim with the sender, block number and deposit.
             Proofs::<T>::insert(
                 &claim, 
-                (receiver.clone(), current_block)
+                ClaimRecord {
+                    creator: sender.clone(),
+                    owner: sender.clone(),
+                    created_at: current_block,
+                    created_time: <pallet_timestamp::Pallet<T>>::get(),
+                    last_transferred_at: None,
+                    deposit,
+                    memo,
+                    history: Default::default(),
+                }
             );
-            //触发事件ClaimTransferred
-            Self::deposit_event(Event::ClaimTransferred(owner, claim, receiver));
 
-            Ok(().into())
+            // Emit an event that the claim was created.
+            Self::deposit_event(Event::ClaimCreated(sender.clone(), claim, deposit));
+
+            Ok(deposit)
         }
 
+        //撤销单个存证，返回退还的押金
+        fn do_revoke_claim(
+            sender: &T::AccountId,
+            claim: ClaimDigest,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            // Get owner of the claim.
+            let record = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+
+            // Verify that sender of the current call is the claim owner.
+            ensure!(*sender == record.owner, Error::<T>::NotProofOwner);
+
+            // Remove claim, its Merkle anchoring and any pending transfer from storage.
+            Proofs::<T>::remove(&claim);
+            MerkleRoots::<T>::remove(&claim);
+            PendingTransfers::<T>::remove(&claim);
+            Self::remove_from_index(sender, &claim);
+
+            //退还存证押金
+            T::Currency::unreserve(sender, record.deposit);
+
+            // Emit an event that the claim was erased.
+            Self::deposit_event(Event::ClaimRevoked(sender.clone(), claim, record.deposit));
+
+            Ok(record.deposit)
+        }
+
+        //记录转移，记录已满时丢弃最早的一条
+        fn push_history(
+            history: &mut HistoryOf<T>,
+            entry: (T::AccountId, T::BlockNumber),
+        ) {
+            if let Err(entry) = history.try_push(entry) {
+                if !history.is_empty() {
+                    history.remove(0);
+                    let _ = history.try_push(entry);
+                }
+            }
+        }
+
+        fn add_to_index(who: &T::AccountId, claim: ClaimDigest) -> DispatchResult {
+            ClaimsOf::<T>::try_mutate(who, |claims| {
+                claims.try_push(claim).map_err(|_| Error::<T>::TooManyClaims.into())
+            })
+        }
+
+        fn remove_from_index(who: &T::AccountId, claim: &ClaimDigest) {
+            let mut claims = ClaimsOf::<T>::get(who);
+            claims.retain(|c| c != claim);
+            if claims.is_empty() {
+                ClaimsOf::<T>::remove(who);
+            } else {
+                ClaimsOf::<T>::insert(who, claims);
+            }
+        }
     }
-}
\ No newline at end of file
+}
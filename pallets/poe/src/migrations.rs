@@ -0,0 +1,71 @@
+//! Storage migrations for the proof of existence pallet.
+
+use crate::{ClaimDigest, ClaimRecord, ClaimRecordOf, ClaimsOf, Config, HashAlgorithm, Pallet, Proofs};
+use frame_support::{
+    storage::{migration::storage_key_iter, unhashed, StoragePrefixedMap},
+    traits::{Get, GetPalletVersion, PalletVersion},
+    weights::Weight,
+    Blake2_128Concat,
+};
+use frame_support::sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+//3.1.0之前的链需要迁移存证的存储布局
+fn is_migrated<T: Config>() -> bool {
+    let version = <Pallet<T> as GetPalletVersion>::storage_version();
+    version.map_or(false, |v| v >= PalletVersion { major: 3, minor: 1, patch: 0 })
+}
+
+//基线的存证布局：以存证原文为键，值为(owner, created_at)
+type LegacyProof<T> = (<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber);
+
+//把以原文为键的存证改为以原文的Blake2-256摘要为键，值保持原样，由migrate_to_claim_record继续转换
+pub fn migrate_raw_claim_keys<T: Config>() -> Weight {
+    if is_migrated::<T>() {
+        return T::DbWeight::get().reads(1);
+    }
+
+    //先取出全部旧存证再写入，避免在同一前缀下边遍历边插入
+    let legacy: Vec<(Vec<u8>, LegacyProof<T>)> = storage_key_iter::<Vec<u8>, LegacyProof<T>, Blake2_128Concat>(
+        Proofs::<T>::module_prefix(),
+        Proofs::<T>::storage_prefix(),
+    ).drain().collect();
+
+    for (raw, proof) in legacy.iter() {
+        let claim = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, raw);
+        unhashed::put(&Proofs::<T>::hashed_key_for(&claim), proof);
+    }
+
+    let migrated = legacy.len() as u64;
+    T::DbWeight::get().reads_writes(migrated + 1, migrated * 2)
+}
+
+//把基线存证从(owner, created_at)迁移为ClaimRecord并重建账户存证索引，需先执行migrate_raw_claim_keys，已迁移过的链不做任何修改
+pub fn migrate_to_claim_record<T: Config>() -> Weight {
+    if is_migrated::<T>() {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut translated = 0u64;
+    Proofs::<T>::translate::<LegacyProof<T>, _>(|claim, (owner, created_at)| {
+        translated += 1;
+
+        //基线存证没有质押押金，押金记为0；账户存证数量超过上限时存证仍然保留，只是不进入索引
+        let _ = ClaimsOf::<T>::try_mutate(&owner, |claims| claims.try_push(claim));
+
+        //基线布局没有记录创建者、创建时间戳和转移记录，用当前拥有者和默认值代替
+        let record: ClaimRecordOf<T> = ClaimRecord {
+            creator: owner.clone(),
+            owner,
+            created_at,
+            created_time: Default::default(),
+            last_transferred_at: None,
+            deposit: Zero::zero(),
+            memo: Default::default(),
+            history: Default::default(),
+        };
+        Some(record)
+    });
+
+    T::DbWeight::get().reads_writes(translated * 2 + 1, translated * 2)
+}
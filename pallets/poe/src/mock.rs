@@ -1,6 +1,6 @@
 use crate as pallet_poe;
 use sp_core::H256;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, traits::GenesisBuild};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup}, testing::Header,
 };
@@ -17,14 +17,24 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		PoeModule: pallet_poe::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 42;
-	pub const ClaimSize: usize = 2;
+	pub const MaxClaimsPerAccount: u32 = 3;
+	pub const ExistentialDeposit: u64 = 1;
+	pub const ClaimDepositBase: u64 = 10;
+	pub const ClaimDepositPerByte: u64 = 1;
+	pub const TransferExpiry: u64 = 10;
+	pub const MinimumPeriod: u64 = 5;
+	pub const MaxMemoLength: u32 = 16;
+	pub const MaxTransferHistory: u32 = 2;
+	pub const MaxBatchSize: u32 = 3;
 }
 
 impl system::Config for Test {
@@ -45,7 +55,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -53,12 +63,49 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
 impl pallet_poe::Config for Test {
 	type Event = Event;
-	type ClaimSize = ClaimSize;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type TransferExpiry = TransferExpiry;
+	type MaxMemoLength = MaxMemoLength;
+	type MaxTransferHistory = MaxTransferHistory;
+	type MaxBatchSize = MaxBatchSize;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	new_test_ext_with_claims(vec![])
+}
+
+//创世区块中预先登记存证：(拥有者, 存证摘要)
+pub fn new_test_ext_with_claims(claims: Vec<(u64, pallet_poe::ClaimDigest)>) -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1_000), (2, 1_000), (3, 1_000), (5, 20)],
+	}.assimilate_storage(&mut t).unwrap();
+	pallet_poe::GenesisConfig::<Test> { claims }.assimilate_storage(&mut t).unwrap();
+	t.into()
 }
@@ -1,17 +1,33 @@
 use crate::{Error, mock::*};
-use frame_support::{assert_ok, assert_noop};
+use frame_support::{
+	assert_ok, assert_noop,
+	storage::{migration::{get_storage_value, put_storage_value}, unhashed, StoragePrefixedMap},
+	traits::OnRuntimeUpgrade,
+	Blake2_128Concat, StorageHasher,
+};
+use codec::Encode;
 use super::*;
 
+fn digest_of(preimage: &[u8]) -> ClaimDigest {
+	ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, preimage)
+}
+
 //创建存证的成功用例
 #[test]
 fn create_claim_works() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0; ClaimSize::get()];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
-		assert_eq!(
-			Proofs::<Test>::get(&claim),
-			Some((1,frame_system::Pallet::<Test>::block_number())
-		));
+		let claim = digest_of(b"document");
+		Timestamp::set_timestamp(42);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, b"ipfs://doc".to_vec()));
+		let record = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!(record.creator, 1);
+		assert_eq!(record.owner, 1);
+		assert_eq!(record.created_at, frame_system::Pallet::<Test>::block_number());
+		assert_eq!(record.created_time, 42);
+		assert_eq!(record.last_transferred_at, None);
+		assert_eq!(record.deposit, PoeModule::claim_deposit(&claim, b"ipfs://doc"));
+		assert_eq!(record.memo.into_inner(), b"ipfs://doc".to_vec());
+		assert!(record.history.is_empty());
 	});
 }
 
@@ -20,10 +36,10 @@ fn create_claim_works() {
 #[test]
 fn create_claim_failed_when_claim_already_exist() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let claim = digest_of(b"document");
+		let _ = PoeModule::create_claim(Origin::signed(1), claim, vec![]);
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(1), claim.clone()),
+			PoeModule::create_claim(Origin::signed(1), claim, vec![]),
 			Error::<Test>::ProofAlreadyExist
 		);
 	})
@@ -33,9 +49,9 @@ fn create_claim_failed_when_claim_already_exist() {
 #[test]
 fn revoke_claim_works() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
-		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+		let claim = digest_of(b"document");
+		let _ = PoeModule::create_claim(Origin::signed(1), claim, vec![]);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim));
 		assert_eq!(Proofs::<Test>::get(&claim), None);
 	})
 
@@ -45,9 +61,9 @@ fn revoke_claim_works() {
 #[test]
 fn revoke_claim_failed_when_claim_is_not_exist() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0, 1];
+		let claim = digest_of(b"document");
 		assert_noop!(
-			PoeModule::revoke_claim(Origin::signed(1), claim.clone()),
+			PoeModule::revoke_claim(Origin::signed(1), claim),
 			Error::<Test>::ClaimNotExist
 		);
 	})
@@ -57,10 +73,10 @@ fn revoke_claim_failed_when_claim_is_not_exist() {
 #[test]
 fn revoke_claim_failed_when_origin_is_not_owner() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0, 1];
-		let _  = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let claim = digest_of(b"document");
+		let _  = PoeModule::create_claim(Origin::signed(1), claim, vec![]);
 		assert_noop!(
-			PoeModule::revoke_claim(Origin::signed(2), claim.clone()),
+			PoeModule::revoke_claim(Origin::signed(2), claim),
 			Error::<Test>::NotProofOwner
 		);
 	})
@@ -70,16 +86,22 @@ fn revoke_claim_failed_when_origin_is_not_owner() {
 #[test]
 fn transfer_claim_works() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let claim = digest_of(b"document");
+		let _ = PoeModule::create_claim(Origin::signed(1), claim, vec![]);
 		
-		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
-		assert_eq!(
-			Proofs::<Test>::get(&claim),
-			Some((2, frame_system::Pallet::<Test>::block_number()))
-		);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		assert_eq!(PendingTransfers::<Test>::get(&claim), Some((2, TransferExpiry::get())));
+		System::set_block_number(3);
+		assert_ok!(PoeModule::accept_claim(Origin::signed(2), claim));
+		let record = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!(record.creator, 1);
+		assert_eq!(record.owner, 2);
+		assert_eq!(record.created_at, 0);
+		assert_eq!(record.last_transferred_at, Some(3));
+		assert_eq!(record.history.into_inner(), vec![(2, 3)]);
+		assert_eq!(PendingTransfers::<Test>::get(&claim), None);
 		assert_noop!(
-			PoeModule::revoke_claim(Origin::signed(1), claim.clone()),
+			PoeModule::revoke_claim(Origin::signed(1), claim),
 			Error::<Test>::NotProofOwner
 		);
 	})	
@@ -89,9 +111,9 @@ fn transfer_claim_works() {
 #[test]
 fn transfer_claim_failed_when_claim_is_not_exist() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0, 1];
+		let claim = digest_of(b"document");
 		assert_noop!(
-			PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2),
+			PoeModule::transfer_claim(Origin::signed(1), claim, 2),
 			Error::<Test>::ClaimNotExist
 		);
 	})
@@ -101,24 +123,576 @@ fn transfer_claim_failed_when_claim_is_not_exist() {
 #[test]
 fn transfer_claim_failed_when_origin_is_not_owner() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let claim = digest_of(b"document");
+		let _ = PoeModule::create_claim(Origin::signed(1), claim, vec![]);
 		assert_noop!(
-			PoeModule::transfer_claim(Origin::signed(3), claim.clone(), 2),
+			PoeModule::transfer_claim(Origin::signed(3), claim, 2),
 			Error::<Test>::NotProofOwner
 		);
 	})
 }
 
 
-//创建存证，当存证内容长度超过上限的失败用例
+//同一原文使用不同哈希算法得到不同的存证
+#[test]
+fn create_claim_with_different_algorithms_works() {
+	new_test_ext().execute_with(||{
+		let blake2 = ClaimDigest::from_preimage(HashAlgorithm::Blake2_256, b"document");
+		let sha2 = ClaimDigest::from_preimage(HashAlgorithm::Sha2_256, b"document");
+		let keccak = ClaimDigest::from_preimage(HashAlgorithm::Keccak256, b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), blake2, vec![]));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), sha2, vec![]));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), keccak, vec![]));
+		assert_eq!(sha2.digest, sp_io::hashing::sha2_256(b"document"));
+		assert_eq!(keccak.digest, sp_io::hashing::keccak_256(b"document"));
+	});
+}
+
+//用原文校验存证的成功用例
 #[test]
-fn create_claim_failed_when_size_too_large() {
+fn verify_preimage_works() {
 	new_test_ext().execute_with(||{
-		let claim = vec![0; ClaimSize::get() + 1];
+		let claim = ClaimDigest::from_preimage(HashAlgorithm::Sha2_256, b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_eq!(
+			PoeModule::verify_preimage(HashAlgorithm::Sha2_256, b"document"),
+			Some((1, frame_system::Pallet::<Test>::block_number()))
+		);
+	});
+}
+
+//用原文校验存证，当原文或算法不匹配的失败用例
+#[test]
+fn verify_preimage_failed_when_preimage_does_not_match() {
+	new_test_ext().execute_with(||{
+		let claim = ClaimDigest::from_preimage(HashAlgorithm::Sha2_256, b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_eq!(PoeModule::verify_preimage(HashAlgorithm::Sha2_256, b"tampered"), None);
+		assert_eq!(PoeModule::verify_preimage(HashAlgorithm::Blake2_256, b"document"), None);
+	});
+}
+
+//创建存证后加入账户存证索引
+#[test]
+fn create_claim_updates_account_index() {
+	new_test_ext().execute_with(||{
+		let claim1 = digest_of(b"document 1");
+		let claim2 = digest_of(b"document 2");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim1, vec![]));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim2, vec![]));
+		assert_eq!(PoeModule::claims_of(1).into_inner(), vec![claim1, claim2]);
+		assert!(PoeModule::claims_of(2).is_empty());
+	});
+}
+
+//创建存证，当账户存证数量达到上限的失败用例
+#[test]
+fn create_claim_failed_when_too_many_claims() {
+	new_test_ext().execute_with(||{
+		for i in 0..MaxClaimsPerAccount::get() {
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), digest_of(&i.to_le_bytes()), vec![]));
+		}
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(1), claim.clone()),
-			Error::<Test>::ClaimSizeTooLarge
+			PoeModule::create_claim(Origin::signed(1), digest_of(b"one too many"), vec![]),
+			Error::<Test>::TooManyClaims
 		);
-	});	
-}
\ No newline at end of file
+	});
+}
+
+//撤销存证后从账户存证索引中移除
+#[test]
+fn revoke_claim_updates_account_index() {
+	new_test_ext().execute_with(||{
+		let claim1 = digest_of(b"document 1");
+		let claim2 = digest_of(b"document 2");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim1, vec![]));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim2, vec![]));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim1));
+		assert_eq!(PoeModule::claims_of(1).into_inner(), vec![claim2]);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim2));
+		assert!(!ClaimsOf::<Test>::contains_key(1));
+	});
+}
+
+//转移存证后更新双方的存证索引
+#[test]
+fn transfer_claim_updates_account_index() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		assert_eq!(PoeModule::claims_of(1).into_inner(), vec![claim]);
+		assert_ok!(PoeModule::accept_claim(Origin::signed(2), claim));
+		assert!(PoeModule::claims_of(1).is_empty());
+		assert_eq!(PoeModule::claims_of(2).into_inner(), vec![claim]);
+	});
+}
+
+//转移存证，当接收者存证数量达到上限的失败用例
+#[test]
+fn transfer_claim_failed_when_receiver_has_too_many_claims() {
+	new_test_ext().execute_with(||{
+		for i in 0..MaxClaimsPerAccount::get() {
+			assert_ok!(PoeModule::create_claim(Origin::signed(2), digest_of(&i.to_le_bytes()), vec![]));
+		}
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(2), claim),
+			Error::<Test>::TooManyClaims
+		);
+	});
+}
+
+//创建存证时质押押金
+#[test]
+fn create_claim_reserves_deposit() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		let deposit = ClaimDepositBase::get() + ClaimDepositPerByte::get() * claim.encoded_size() as u64;
+		assert_eq!(PoeModule::claim_deposit(&claim, &[]), deposit);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(Balances::free_balance(1), 1_000 - deposit);
+	});
+}
+
+//创建存证，当余额不足以质押押金的失败用例
+#[test]
+fn create_claim_failed_when_balance_is_insufficient() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(5), claim, vec![]),
+			Error::<Test>::InsufficientBalance
+		);
+		assert!(PoeModule::claims_of(5).is_empty());
+		assert_eq!(Balances::reserved_balance(5), 0);
+	});
+}
+
+//撤销存证时退还押金
+#[test]
+fn revoke_claim_unreserves_deposit() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+	});
+}
+
+//转移存证时押金转移给接收者
+#[test]
+fn transfer_claim_moves_deposit_to_receiver() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		let deposit = PoeModule::claim_deposit(&claim, &[]);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_ok!(PoeModule::accept_claim(Origin::signed(2), claim));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000 - deposit);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim));
+		assert_eq!(Balances::free_balance(2), 1_000 + deposit);
+	});
+}
+
+//转移存证给自己的失败用例
+#[test]
+fn transfer_claim_failed_when_receiver_is_owner() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(1), claim, 1),
+			Error::<Test>::TransferToSelf
+		);
+	});
+}
+
+//接受存证转移，当没有转移请求的失败用例
+#[test]
+fn accept_claim_failed_when_no_pending_transfer() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(2), claim),
+			Error::<Test>::NoPendingTransfer
+		);
+	});
+}
+
+//接受存证转移，当调用者不是接收者的失败用例
+#[test]
+fn accept_claim_failed_when_origin_is_not_receiver() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(3), claim),
+			Error::<Test>::NotTransferReceiver
+		);
+	});
+}
+
+//接受存证转移，当转移请求已过期的失败用例
+#[test]
+fn accept_claim_failed_when_transfer_expired() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		System::set_block_number(TransferExpiry::get() + 1);
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(2), claim),
+			Error::<Test>::TransferExpired
+		);
+	});
+}
+
+//取消存证转移的成功用例
+#[test]
+fn cancel_claim_transfer_works() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		assert_noop!(
+			PoeModule::cancel_claim_transfer(Origin::signed(2), claim),
+			Error::<Test>::NotProofOwner
+		);
+		assert_ok!(PoeModule::cancel_claim_transfer(Origin::signed(1), claim));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(2), claim),
+			Error::<Test>::NoPendingTransfer
+		);
+	});
+}
+
+//撤销存证时清除转移请求
+#[test]
+fn revoke_claim_clears_pending_transfer() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim, 2));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim));
+		assert_eq!(PendingTransfers::<Test>::get(&claim), None);
+	});
+}
+
+//创建存证，当备注超过最大长度的失败用例
+#[test]
+fn create_claim_failed_when_memo_too_long() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), claim, vec![0; MaxMemoLength::get() as usize + 1]),
+			Error::<Test>::MemoTooLong
+		);
+	});
+}
+
+//备注按字节计入押金
+#[test]
+fn create_claim_with_memo_reserves_more_deposit() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		let memo = vec![1; MaxMemoLength::get() as usize];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, memo.clone()));
+		assert_eq!(
+			Balances::reserved_balance(1),
+			PoeModule::claim_deposit(&claim, &[]) + ClaimDepositPerByte::get() * memo.len() as u64
+		);
+	});
+}
+
+//转移记录超过上限时丢弃最早的记录
+#[test]
+fn transfer_history_keeps_latest_entries() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim, vec![]));
+		for (block, (from, to)) in [(1, 2), (2, 3), (3, 1)].iter().enumerate() {
+			System::set_block_number(block as u64 + 1);
+			assert_ok!(PoeModule::transfer_claim(Origin::signed(*from), claim, *to));
+			assert_ok!(PoeModule::accept_claim(Origin::signed(*to), claim));
+		}
+		let record = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!(record.creator, 1);
+		assert_eq!(record.created_at, 0);
+		assert_eq!(record.history.into_inner(), vec![(3, 2), (1, 3)]);
+	});
+}
+
+//以原文为键的基线存证迁移为以Blake2-256摘要为键
+#[test]
+fn migrate_raw_claim_keys_works() {
+	new_test_ext().execute_with(||{
+		let raw = b"legacy document".to_vec();
+		let legacy_key = Blake2_128Concat::hash(&raw.encode());
+		put_storage_value(Proofs::<Test>::module_prefix(), Proofs::<Test>::storage_prefix(), &legacy_key, (2u64, 5u64));
+
+		crate::migrations::migrate_raw_claim_keys::<Test>();
+
+		let claim = digest_of(&raw);
+		assert_eq!(unhashed::get::<(u64, u64)>(&Proofs::<Test>::hashed_key_for(&claim)), Some((2, 5)));
+		assert_eq!(
+			get_storage_value::<(u64, u64)>(Proofs::<Test>::module_prefix(), Proofs::<Test>::storage_prefix(), &legacy_key),
+			None
+		);
+	});
+}
+
+//基线存证经过键和值的迁移成为ClaimRecord：押金为0，重建账户存证索引
+#[test]
+fn migrate_to_claim_record_works() {
+	new_test_ext().execute_with(||{
+		let raw = b"legacy document".to_vec();
+		put_storage_value(
+			Proofs::<Test>::module_prefix(),
+			Proofs::<Test>::storage_prefix(),
+			&Blake2_128Concat::hash(&raw.encode()),
+			(2u64, 5u64),
+		);
+
+		PoeModule::on_runtime_upgrade();
+
+		let claim = digest_of(&raw);
+		let record = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!(record.creator, 2);
+		assert_eq!(record.owner, 2);
+		assert_eq!(record.created_at, 5);
+		assert_eq!(record.last_transferred_at, None);
+		assert_eq!(record.deposit, 0);
+		assert!(record.memo.is_empty());
+		assert!(record.history.is_empty());
+		assert_eq!(PoeModule::claims_of(2).into_inner(), vec![claim]);
+		assert_eq!(PoeModule::verify_preimage(HashAlgorithm::Blake2_256, &raw), Some((2, 5)));
+
+		//迁移后的存证可以正常撤销，不退还押金
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+//批量创建存证的成功用例
+#[test]
+fn create_claims_works() {
+	new_test_ext().execute_with(||{
+		let claims = vec![digest_of(b"document 1"), digest_of(b"document 2")];
+		assert_ok!(PoeModule::create_claims(Origin::signed(1), claims.clone()));
+		assert_eq!(PoeModule::claims_of(1).into_inner(), claims);
+		assert_eq!(
+			Balances::reserved_balance(1),
+			PoeModule::claim_deposit(&claims[0], &[]) + PoeModule::claim_deposit(&claims[1], &[])
+		);
+	});
+}
+
+//批量创建存证，当其中一个存证已存在时全部回滚的失败用例
+#[test]
+fn create_claims_failed_when_one_claim_already_exist() {
+	new_test_ext().execute_with(||{
+		let existing = digest_of(b"document 2");
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), existing, vec![]));
+		assert_noop!(
+			PoeModule::create_claims(Origin::signed(1), vec![digest_of(b"document 1"), existing]),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert!(PoeModule::claims_of(1).is_empty());
+	});
+}
+
+//批量创建存证，当批次中有重复存证的失败用例
+#[test]
+fn create_claims_failed_when_batch_has_duplicates() {
+	new_test_ext().execute_with(||{
+		let claim = digest_of(b"document");
+		assert_noop!(
+			PoeModule::create_claims(Origin::signed(1), vec![claim, claim]),
+			Error::<Test>::ProofAlreadyExist
+		);
+	});
+}
+
+//批量创建存证，当批次超过上限的失败用例
+#[test]
+fn create_claims_failed_when_batch_too_large() {
+	new_test_ext().execute_with(||{
+		let claims = (0..MaxBatchSize::get() + 1).map(|i| digest_of(&i.to_le_bytes())).collect();
+		assert_noop!(
+			PoeModule::create_claims(Origin::signed(1), claims),
+			Error::<Test>::BatchTooLarge
+		);
+	});
+}
+
+//批量撤销存证的成功用例
+#[test]
+fn revoke_claims_works() {
+	new_test_ext().execute_with(||{
+		let claims = vec![digest_of(b"document 1"), digest_of(b"document 2")];
+		assert_ok!(PoeModule::create_claims(Origin::signed(1), claims.clone()));
+		assert_ok!(PoeModule::revoke_claims(Origin::signed(1), claims.clone()));
+		assert!(PoeModule::claims_of(1).is_empty());
+		assert_eq!(Proofs::<Test>::get(&claims[0]), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+//批量撤销存证，当其中一个存证不属于调用者时全部回滚的失败用例
+#[test]
+fn revoke_claims_failed_when_one_claim_is_not_owned() {
+	new_test_ext().execute_with(||{
+		let mine = digest_of(b"document 1");
+		let theirs = digest_of(b"document 2");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), mine, vec![]));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), theirs, vec![]));
+		assert_noop!(
+			PoeModule::revoke_claims(Origin::signed(1), vec![mine, theirs]),
+			Error::<Test>::NotProofOwner
+		);
+		assert!(Proofs::<Test>::contains_key(&mine));
+	});
+}
+
+fn hash_leaf(leaf: &[u8; 32]) -> [u8; 32] {
+	PoeModule::hash_merkle_leaf(HashAlgorithm::Blake2_256, leaf)
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+	let mut pair = vec![MERKLE_NODE_PREFIX];
+	pair.extend_from_slice(left);
+	pair.extend_from_slice(right);
+	sp_io::hashing::blake2_256(&pair)
+}
+
+//构造四个叶子的Merkle树，返回文档摘要和根
+fn merkle_tree() -> (Vec<[u8; 32]>, ClaimDigest) {
+	let leaves: Vec<[u8; 32]> = (0u8..4).map(|i| sp_io::hashing::blake2_256(&[i])).collect();
+	let left = hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1]));
+	let right = hash_pair(&hash_leaf(&leaves[2]), &hash_leaf(&leaves[3]));
+	let root = ClaimDigest { algorithm: HashAlgorithm::Blake2_256, digest: hash_pair(&left, &right) };
+	(leaves, root)
+}
+
+//锚定Merkle根并校验包含证明的成功用例
+#[test]
+fn anchor_merkle_root_and_verify_inclusion_works() {
+	new_test_ext().execute_with(||{
+		let (leaves, root) = merkle_tree();
+		System::set_block_number(5);
+		assert_ok!(PoeModule::anchor_merkle_root(Origin::signed(1), root, 4, vec![]));
+		assert_eq!(MerkleRoots::<Test>::get(&root), Some(4));
+
+		let path = vec![
+			MerkleProofNode::Left(hash_leaf(&leaves[2])),
+			MerkleProofNode::Left(hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1]))),
+		];
+		assert_eq!(PoeModule::verify_inclusion(leaves[3], &path, root), Some((5, 1)));
+	});
+}
+
+//校验包含证明，当叶子或路径不匹配的失败用例
+#[test]
+fn verify_inclusion_failed_when_proof_does_not_match() {
+	new_test_ext().execute_with(||{
+		let (leaves, root) = merkle_tree();
+		assert_ok!(PoeModule::anchor_merkle_root(Origin::signed(1), root, 4, vec![]));
+
+		let path = vec![
+			MerkleProofNode::Right(hash_leaf(&leaves[3])),
+			MerkleProofNode::Right(hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1]))),
+		];
+		assert_eq!(PoeModule::verify_inclusion(leaves[3], &path, root), None);
+		assert_eq!(PoeModule::verify_inclusion(leaves[0], &path, root), None);
+	});
+}
+
+//校验包含证明，当把内部节点当作文档摘要的失败用例
+#[test]
+fn verify_inclusion_failed_when_leaf_is_internal_node() {
+	new_test_ext().execute_with(||{
+		let (leaves, root) = merkle_tree();
+		assert_ok!(PoeModule::anchor_merkle_root(Origin::signed(1), root, 4, vec![]));
+
+		let left = hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1]));
+		let right = hash_pair(&hash_leaf(&leaves[2]), &hash_leaf(&leaves[3]));
+		assert_eq!(PoeModule::verify_inclusion(left, &[MerkleProofNode::Right(right)], root), None);
+	});
+}
+
+//校验包含证明，当路径长于树高的失败用例
+#[test]
+fn verify_inclusion_failed_when_path_is_too_long() {
+	new_test_ext().execute_with(||{
+		let (leaves, root) = merkle_tree();
+		let path = vec![
+			MerkleProofNode::Left(hash_leaf(&leaves[2])),
+			MerkleProofNode::Left(hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1]))),
+		];
+
+		//只有两个叶子的树高为1，两层的路径不可能属于这棵树
+		assert_ok!(PoeModule::anchor_merkle_root(Origin::signed(1), root, 2, vec![]));
+		assert_eq!(PoeModule::verify_inclusion(leaves[3], &path, root), None);
+	});
+}
+
+//校验包含证明，当根只是普通存证或已被撤销的失败用例
+#[test]
+fn verify_inclusion_failed_when_root_is_not_anchored() {
+	new_test_ext().execute_with(||{
+		let (leaves, root) = merkle_tree();
+		let path = vec![
+			MerkleProofNode::Left(hash_leaf(&leaves[2])),
+			MerkleProofNode::Left(hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1]))),
+		];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), root, vec![]));
+		assert_eq!(PoeModule::verify_inclusion(leaves[3], &path, root), None);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), root));
+
+		assert_ok!(PoeModule::anchor_merkle_root(Origin::signed(1), root, 4, vec![]));
+		assert_eq!(PoeModule::verify_inclusion(leaves[3], &path, root), Some((0, 1)));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), root));
+		assert_eq!(MerkleRoots::<Test>::get(&root), None);
+		assert_eq!(PoeModule::verify_inclusion(leaves[3], &path, root), None);
+	});
+}
+
+//锚定Merkle根，当叶子数量为零的失败用例
+#[test]
+fn anchor_merkle_root_failed_when_tree_is_empty() {
+	new_test_ext().execute_with(||{
+		let (_, root) = merkle_tree();
+		assert_noop!(
+			PoeModule::anchor_merkle_root(Origin::signed(1), root, 0, vec![]),
+			Error::<Test>::EmptyMerkleTree
+		);
+	});
+}
+
+//创世配置预先登记存证的用例
+#[test]
+fn genesis_config_works() {
+	let claim = digest_of(b"genesis document");
+	new_test_ext_with_claims(vec![(1, claim)]).execute_with(||{
+		let record = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!(record.owner, 1);
+		assert_eq!(record.creator, 1);
+		assert_eq!(record.deposit, PoeModule::claim_deposit(&claim, &[]));
+		assert_eq!(Balances::reserved_balance(1), record.deposit);
+		assert_eq!(PoeModule::claims_of(1).into_inner(), vec![claim]);
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(2), claim, vec![]),
+			Error::<Test>::ProofAlreadyExist
+		);
+	});
+}
@@ -0,0 +1,130 @@
+//! Weights for pallet_poe
+//!
+//! The read and write counts are derived by hand from the storage each call touches on its
+//! worst-case path, counting every account whose balance the call changes. The execution times
+//! are estimates that have not been measured on reference hardware yet; run
+//! `scripts/benchmark.sh` to replace this file with the output of the Substrate benchmark CLI.
+
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim(m: u32, ) -> Weight;
+	fn revoke_claim() -> Weight;
+	fn create_claims(n: u32, ) -> Weight;
+	fn revoke_claims(n: u32, ) -> Weight;
+	fn anchor_merkle_root(m: u32, ) -> Weight;
+	fn transfer_claim() -> Weight;
+	fn accept_claim() -> Weight;
+	fn cancel_claim_transfer() -> Weight;
+}
+
+/// Weights for pallet_poe using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_claim(m: u32, ) -> Weight {
+		(48_312_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn revoke_claim() -> Weight {
+		(45_871_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn create_claims(n: u32, ) -> Weight {
+		(3_917_000 as Weight)
+			.saturating_add((42_608_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn revoke_claims(n: u32, ) -> Weight {
+		(4_102_000 as Weight)
+			.saturating_add((39_554_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	fn anchor_merkle_root(m: u32, ) -> Weight {
+		(51_026_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn transfer_claim() -> Weight {
+		(21_340_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn accept_claim() -> Weight {
+		(68_725_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn cancel_claim_transfer() -> Weight {
+		(19_863_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_claim(m: u32, ) -> Weight {
+		(48_312_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn revoke_claim() -> Weight {
+		(45_871_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn create_claims(n: u32, ) -> Weight {
+		(3_917_000 as Weight)
+			.saturating_add((42_608_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn revoke_claims(n: u32, ) -> Weight {
+		(4_102_000 as Weight)
+			.saturating_add((39_554_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	fn anchor_merkle_root(m: u32, ) -> Weight {
+		(51_026_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn transfer_claim() -> Weight {
+		(21_340_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn accept_claim() -> Weight {
+		(68_725_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn cancel_claim_transfer() -> Weight {
+		(19_863_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
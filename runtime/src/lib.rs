@@ -13,7 +13,7 @@ use sp_runtime::{
 	transaction_validity::{TransactionValidity, TransactionSource},
 };
 use sp_runtime::traits::{
-	BlakeTwo256, Block as BlockT, AccountIdLookup, Verify, IdentifyAccount, NumberFor,
+	AccountIdConversion, BlakeTwo256, Block as BlockT, AccountIdLookup, Verify, IdentifyAccount, NumberFor,
 };
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -30,8 +30,8 @@ pub use pallet_timestamp::Call as TimestampCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use sp_runtime::{Permill, Perbill};
 pub use frame_support::{
-	construct_runtime, parameter_types, StorageValue,
-	traits::{KeyOwnerProofSystem, Randomness},
+	construct_runtime, parameter_types, StorageValue, PalletId,
+	traits::{Currency, KeyOwnerProofSystem, OnUnbalanced, Randomness},
 	weights::{
 		Weight, IdentityFee,
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -42,6 +42,7 @@ use pallet_transaction_payment::CurrencyAdapter;
 /// Import the template pallet.
 pub use pallet_template;
 pub use pallet_kitties;
+pub use pallet_poe;
 
 
 /// An index to a block.
@@ -99,10 +100,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 };
 
 /// This determines the average expected block time that we are targeting.
@@ -255,7 +256,6 @@ impl pallet_balances::Config for Runtime {
 
 parameter_types! {
 	pub const TransactionByteFee: Balance = 1;
-	pub const ClaimSize: usize = 2;
 }
 
 impl pallet_transaction_payment::Config for Runtime {
@@ -276,6 +276,51 @@ impl pallet_template::Config for Runtime {
 }
 parameter_types! {
 	pub const KittyReserveMoney: u32 = 1_000_000_000;
+	pub const MaxKittiesOwned: u32 = 100;
+	pub const MaxAncestryDepth: u32 = 8;
+	pub const BaseCooldown: BlockNumber = 10 * MINUTES;
+	pub const GestationPeriod: BlockNumber = HOURS;
+	pub const MaxBirthsPerBlock: u32 = 50;
+	pub const MaxAuctionDuration: BlockNumber = 7 * DAYS;
+	pub const MaxAuctionsPerBlock: u32 = 50;
+	pub const AntiSnipeDuration: BlockNumber = 10 * MINUTES;
+	pub const MinBidIncrement: Balance = 1_000_000_000;
+	pub const MarketplaceFee: Perbill = Perbill::from_percent(2);
+	pub const CreatorRoyalty: Perbill = Perbill::from_percent(5);
+	/// Id of the keyless treasury account that receives kitty marketplace fees by default.
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const MaxOffersPerKitty: u32 = 20;
+	pub const MaxOffersPerBidder: u32 = 50;
+	pub const MaxOfferDuration: BlockNumber = 30 * DAYS;
+	pub const MaxKittyNameLength: u32 = 32;
+	pub const MaxKittyMetadataLength: u32 = 256;
+	pub const KittyMetadataDepositPerByte: Balance = 10_000_000;
+	pub const UniqueKittyNames: bool = true;
+	/// Account receiving kitty marketplace fees. `None` sends them to the treasury account. Root
+	/// can point it elsewhere with `system.set_storage`, using `MarketplaceFeeAccount::key()`
+	/// (`twox_128(b":MarketplaceFeeAccount:")`) as the key and a SCALE-encoded
+	/// `Option<AccountId>` as the value.
+	pub storage MarketplaceFeeAccount: Option<AccountId> = None;
+}
+
+/// Deposits kitty marketplace fees into `MarketplaceFeeAccount`, or the treasury account if
+/// none is set.
+///
+/// The treasury account is endowed with the existential deposit at genesis so that fees of any
+/// size can be credited to it. A fee below the existential deposit that is paid to an account
+/// which does not exist yet cannot be credited and is burned, reducing total issuance.
+pub struct MarketplaceFeeToAccount;
+impl MarketplaceFeeToAccount {
+	/// The keyless account derived from `TreasuryPalletId`.
+	pub fn treasury_account() -> AccountId {
+		TreasuryPalletId::get().into_account()
+	}
+}
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for MarketplaceFeeToAccount {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		let destination = MarketplaceFeeAccount::get().unwrap_or_else(Self::treasury_account);
+		Balances::resolve_creating(&destination, amount);
+	}
 }
 /// Configure the pallet-kitties in pallets/kitties.
 impl pallet_kitties::Config for Runtime {
@@ -284,12 +329,49 @@ impl pallet_kitties::Config for Runtime {
     type KittyIndex =  u32;
 	type KittyReserveMoney = KittyReserveMoney;
     type Currency = Balances;
+	type MaxKittiesOwned = MaxKittiesOwned;
+	type MaxAncestryDepth = MaxAncestryDepth;
+	type BaseCooldown = BaseCooldown;
+	type GestationPeriod = GestationPeriod;
+	type MaxBirthsPerBlock = MaxBirthsPerBlock;
+	type MaxAuctionDuration = MaxAuctionDuration;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type AntiSnipeDuration = AntiSnipeDuration;
+	type MinBidIncrement = MinBidIncrement;
+	type MarketplaceFee = MarketplaceFee;
+	type CreatorRoyalty = CreatorRoyalty;
+	type OnMarketplaceFee = MarketplaceFeeToAccount;
+	type MaxOffersPerKitty = MaxOffersPerKitty;
+	type MaxOffersPerBidder = MaxOffersPerBidder;
+	type MaxOfferDuration = MaxOfferDuration;
+	type MaxNameLength = MaxKittyNameLength;
+	type MaxMetadataLength = MaxKittyMetadataLength;
+	type MetadataDepositPerByte = KittyMetadataDepositPerByte;
+	type UniqueNames = UniqueKittyNames;
+	type WeightInfo = pallet_kitties::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxClaimsPerAccount: u32 = 1_000;
+	pub const ClaimDepositBase: Balance = 1_000_000_000;
+	pub const ClaimDepositPerByte: Balance = 10_000_000;
+	pub const ClaimTransferExpiry: BlockNumber = 7 * DAYS;
+	pub const MaxClaimMemoLength: u32 = 256;
+	pub const MaxClaimTransferHistory: u32 = 16;
+	pub const MaxClaimBatchSize: u32 = 100;
+}
 /// Configure the pallet-poe in pallets/poe.
 impl pallet_poe::Config for Runtime {
 	type Event = Event;
-	type ClaimSize = ClaimSize;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type TransferExpiry = ClaimTransferExpiry;
+	type MaxMemoLength = MaxClaimMemoLength;
+	type MaxTransferHistory = MaxClaimTransferHistory;
+	type MaxBatchSize = MaxClaimBatchSize;
+	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -309,8 +391,8 @@ construct_runtime!(
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
 		// Include the custom logic from the pallet-template in the runtime.
 		TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
-		KittiesModule: pallet_kitties::{Pallet, Call, Storage, Event<T>},
-		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>},
+		KittiesModule: pallet_kitties::{Pallet, Call, Storage, Config<T>, Event<T>},
+		PoeModule: pallet_poe::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -467,6 +549,41 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber> for Runtime {
+		fn verify_preimage(
+			algorithm: pallet_poe::HashAlgorithm,
+			preimage: Vec<u8>,
+		) -> Option<(AccountId, BlockNumber)> {
+			PoeModule::verify_preimage(algorithm, &preimage)
+		}
+
+		fn claims_of(account: AccountId) -> Vec<pallet_poe::ClaimDigest> {
+			PoeModule::claims_of(account).into()
+		}
+
+		fn verify_inclusion(
+			leaf: [u8; 32],
+			path: Vec<pallet_poe::MerkleProofNode>,
+			root: pallet_poe::ClaimDigest,
+		) -> Option<(BlockNumber, AccountId)> {
+			PoeModule::verify_inclusion(leaf, &path, root)
+		}
+	}
+
+	impl pallet_kitties_runtime_api::KittiesApi<Block, u32> for Runtime {
+		fn decode_traits(kitty_id: u32) -> Option<pallet_kitties::genome::KittyTraits> {
+			KittiesModule::decode_traits(kitty_id)
+		}
+
+		fn ancestry(kitty_id: u32, depth: u32) -> Vec<(u32, Option<(u32, u32)>)> {
+			KittiesModule::ancestry(kitty_id, depth)
+		}
+
+		fn supply_stats() -> pallet_kitties::SupplyStats {
+			KittiesModule::supply_stats()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn dispatch_benchmark(
@@ -497,6 +614,8 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, pallet_template, TemplateModule);
+			add_benchmark!(params, batches, pallet_poe, PoeModule);
+			add_benchmark!(params, batches, pallet_kitties, KittiesModule);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+# This script is meant to be run on Unix/Linux based systems
+set -e
+
+echo "*** Regenerating pallet weights"
+
+cd $(dirname ${BASH_SOURCE[0]})/..
+
+cargo build --release -p node-template --features runtime-benchmarks
+
+for pallet in poe kitties; do
+	./target/release/node-template benchmark \
+		--chain=dev \
+		--execution=wasm \
+		--wasm-execution=compiled \
+		--pallet=pallet_$pallet \
+		--extrinsic='*' \
+		--steps=50 \
+		--repeat=20 \
+		--output=./pallets/$pallet/src/weights.rs
+done